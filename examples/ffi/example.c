@@ -0,0 +1,77 @@
+/*
+ * plays the opening of a game through the C interface
+ *
+ *   cargo build --features ffi
+ *   cc examples/ffi/example.c -Iinclude -Ltarget/debug -lpusoy_dos -o target/ffi_example
+ *   LD_LIBRARY_PATH=target/debug ./target/ffi_example
+ */
+
+#include <assert.h>
+#include <stdio.h>
+
+#include "pusoy_dos.h"
+
+#define THREE_OF_CLUBS 0
+#define FOUR_OF_CLUBS 4
+#define THREE_OF_HEARTS 1
+
+int main(void) {
+    uint64_t players[] = { 1, 2, 3, 4 };
+    PdGame *game = NULL;
+
+    assert(pd_game_new(players, 4, 1, 42, &game) == PD_OK);
+
+    uint64_t first;
+    assert(pd_game_next_player(game, &first) == PD_OK);
+
+    uint32_t hand[64];
+    size_t hand_len;
+    assert(pd_game_hand(game, first, hand, 64, &hand_len) == PD_OK);
+    assert(hand_len >= 13);
+
+    /* a buffer that is too small still reports the length needed */
+    size_t needed;
+    assert(pd_game_hand(game, first, hand, 1, &needed) == PD_ERR_BUFFER_TOO_SMALL);
+    assert(needed == hand_len);
+
+    assert(pd_game_hand(game, 99, hand, 64, &hand_len) == PD_ERR_INVALID_PLAYER);
+
+    uint32_t bad_code[] = { 200 };
+    assert(pd_game_play(game, first, bad_code, 1) == PD_ERR_INVALID_CARD);
+
+    uint32_t not_a_move[] = { THREE_OF_CLUBS, FOUR_OF_CLUBS };
+    assert(pd_game_play(game, first, not_a_move, 2) == PD_ERR_INVALID_MOVE);
+
+    /* the first player holds the three of clubs, so nobody else does */
+    uint64_t other = first == 1 ? 2 : 1;
+    uint32_t three_of_clubs[] = { THREE_OF_CLUBS };
+    assert(pd_game_play(game, other, three_of_clubs, 1) == PD_ERR_CARDS_NOT_HELD);
+
+    assert(pd_game_play(game, first, three_of_clubs, 1) == PD_OK);
+
+    uint32_t last[5];
+    size_t last_len;
+    assert(pd_game_last_move(game, last, 5, &last_len) == PD_OK);
+    assert(last_len == 1 && last[0] == THREE_OF_CLUBS);
+
+    uint64_t next;
+    assert(pd_game_next_player(game, &next) == PD_OK);
+    assert(next != first);
+
+    /* playing again out of turn is refused */
+    uint32_t card[] = { hand[0] == THREE_OF_CLUBS ? hand[1] : hand[0] };
+    assert(pd_game_play(game, first, card, 1) == PD_ERR_MOVE_NOT_ALLOWED);
+
+    assert(pd_game_play(game, next, NULL, 0) == PD_OK);
+
+    uint64_t winners[4];
+    size_t winners_len;
+    assert(pd_game_winners(game, winners, 4, &winners_len) == PD_OK);
+    assert(winners_len == 0);
+    assert(!pd_game_is_reversed(game));
+
+    pd_game_free(game);
+
+    printf("ok\n");
+    return 0;
+}
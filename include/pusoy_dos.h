@@ -0,0 +1,201 @@
+#ifndef PUSOY_DOS_H
+#define PUSOY_DOS_H
+
+/* generated by cbindgen from src/ffi.rs - do not edit */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/*
+ success
+ */
+#define PD_OK 0
+
+/*
+ a required pointer was null
+ */
+#define PD_ERR_NULL_POINTER 1
+
+/*
+ the cards do not make a valid move
+ */
+#define PD_ERR_INVALID_MOVE 2
+
+/*
+ no player with that id is in the game
+ */
+#define PD_ERR_INVALID_PLAYER 3
+
+/*
+ the player does not hold the cards
+ */
+#define PD_ERR_CARDS_NOT_HELD 4
+
+/*
+ the move cannot be played now - out of turn, or too low
+ */
+#define PD_ERR_MOVE_NOT_ALLOWED 5
+
+/*
+ a card code is out of range
+ */
+#define PD_ERR_INVALID_CARD 6
+
+/*
+ the output buffer is too small - the needed length is still written
+ */
+#define PD_ERR_BUFFER_TOO_SMALL 7
+
+/*
+ the game could not be dealt
+ */
+#define PD_ERR_SETUP_FAILED 8
+
+/*
+ the game was won on the deal, so no cards can be played
+ */
+#define PD_ERR_WON_ON_DEAL 9
+
+/*
+ the player must play their highest single
+ */
+#define PD_ERR_HIGHEST_SINGLE_REQUIRED 10
+
+/*
+ the player must announce their last card
+ */
+#define PD_ERR_LAST_CARD_NOT_ANNOUNCED 11
+
+/*
+ a last card was announced for a move that does not leave one
+ */
+#define PD_ERR_LAST_CARD_WRONGLY_ANNOUNCED 12
+
+/*
+ any other error from the engine
+ */
+#define PD_ERR_OTHER 99
+
+/*
+ card code for a joker held in a hand
+ */
+#define PD_JOKER 52
+
+/*
+ added to a card code to play a joker as that card
+ */
+#define PD_WILDCARD 64
+
+/*
+ a game in progress
+ */
+typedef struct PdGame PdGame;
+
+/*
+ deal a new game between `num_players` players, shuffled with `seed`
+
+ # Safety
+
+ `player_ids` must point to `num_players` ids and `out` must be writable
+ */
+int32_t pd_game_new(const uint64_t *player_ids,
+                    size_t num_players,
+                    size_t num_decks,
+                    uint64_t seed,
+                    struct PdGame **out);
+
+/*
+ release a game - passing null does nothing
+
+ # Safety
+
+ `game` must have come from `pd_game_new` and not already been freed
+ */
+void pd_game_free(struct PdGame *game);
+
+/*
+ play `num_cards` card codes for a player - no cards is a pass
+
+ # Safety
+
+ `game` must be a live handle and `cards` must point to `num_cards` codes
+ */
+int32_t pd_game_play(struct PdGame *game,
+                     uint64_t player_id,
+                     const uint32_t *cards,
+                     size_t num_cards);
+
+/*
+ play `num_cards` card codes for a player, saying whether they have
+ announced the move leaves them with their last card
+
+ # Safety
+
+ `game` must be a live handle and `cards` must point to `num_cards` codes
+ */
+int32_t pd_game_play_announcing(struct PdGame *game,
+                                uint64_t player_id,
+                                const uint32_t *cards,
+                                size_t num_cards,
+                                bool last_card);
+
+/*
+ write a player's hand as card codes
+
+ # Safety
+
+ `game` must be a live handle, `out` must have room for `capacity`
+ codes and `out_len` must be writable
+ */
+int32_t pd_game_hand(const struct PdGame *game,
+                     uint64_t player_id,
+                     uint32_t *out,
+                     size_t capacity,
+                     size_t *out_len);
+
+/*
+ write the id of the player whose turn it is
+
+ # Safety
+
+ `game` must be a live handle and `out` must be writable
+ */
+int32_t pd_game_next_player(const struct PdGame *game, uint64_t *out);
+
+/*
+ write the cards of the move to beat - none after a pass or at the
+ start of a round
+
+ # Safety
+
+ `game` must be a live handle, `out` must have room for `capacity`
+ codes and `out_len` must be writable
+ */
+int32_t pd_game_last_move(const struct PdGame *game,
+                          uint32_t *out,
+                          size_t capacity,
+                          size_t *out_len);
+
+/*
+ write the ids of players who have gone out, in finishing order
+
+ # Safety
+
+ `game` must be a live handle, `out` must have room for `capacity`
+ ids and `out_len` must be writable
+ */
+int32_t pd_game_winners(const struct PdGame *game, uint64_t *out, size_t capacity, size_t *out_len);
+
+/*
+ whether the card order is currently reversed
+
+ # Safety
+
+ `game` must be a live handle
+ */
+bool pd_game_is_reversed(const struct PdGame *game);
+
+#endif  /* PUSOY_DOS_H */
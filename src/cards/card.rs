@@ -1,5 +1,6 @@
 use std::fmt;
 use std::cmp::Ordering;
+use std::str::FromStr;
 
 use cards::types::*;
 
@@ -125,6 +126,80 @@ impl fmt::Display for Card {
 
 
 
+impl FromStr for PlayerCard {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<PlayerCard, &'static str> {
+        parse_card(s)
+    }
+}
+
+/// parse a card such as "3C", "10H" or "Q♠"
+///
+/// a leading "*" makes a wildcard, such as "*2S", and "JK" is a
+/// joker - "JK1" is the joker numbered 1, and "JK" alone is joker 0
+pub fn parse_card(text: &str) -> Result<PlayerCard, &'static str> {
+    let text = text.trim().to_uppercase();
+
+    if let Some(number) = text.strip_prefix("JK") {
+        return match number {
+            "" => Ok(PlayerCard::Joker(0)),
+            n => n.parse().map(PlayerCard::Joker).map_err(|_| "Unknown joker")
+        };
+    }
+
+    let (wildcard, text) = match text.strip_prefix('*') {
+        Some(rest) => (true, rest),
+        None => (false, &text[..])
+    };
+
+    let suit_char = text.chars().last().ok_or("Card is empty")?;
+    let suit = match suit_char {
+        'C' | '♣' => Suit::Clubs,
+        'H' | '♥' => Suit::Hearts,
+        'D' | '♦' => Suit::Diamonds,
+        'S' | '♠' => Suit::Spades,
+        _ => return Err("Unknown suit")
+    };
+
+    let rank = parse_rank(&text[..text.len() - suit_char.len_utf8()])?;
+    let card = Card::new(rank, suit, false);
+
+    if wildcard {
+        Ok(PlayerCard::Wildcard(card))
+    } else {
+        Ok(PlayerCard::Card(card))
+    }
+}
+
+/// parse a rank such as "3", "10" or "K"
+pub fn parse_rank(text: &str) -> Result<Rank, &'static str> {
+    match &text.trim().to_uppercase()[..] {
+        "3" => Ok(Rank::Three),
+        "4" => Ok(Rank::Four),
+        "5" => Ok(Rank::Five),
+        "6" => Ok(Rank::Six),
+        "7" => Ok(Rank::Seven),
+        "8" => Ok(Rank::Eight),
+        "9" => Ok(Rank::Nine),
+        "10" | "T" => Ok(Rank::Ten),
+        "J" => Ok(Rank::Jack),
+        "Q" => Ok(Rank::Queen),
+        "K" => Ok(Rank::King),
+        "A" => Ok(Rank::Ace),
+        "2" => Ok(Rank::Two),
+        _ => Err("Unknown rank")
+    }
+}
+
+/// parse cards separated by spaces or commas
+pub fn parse_cards(text: &str) -> Result<Vec<PlayerCard>, &'static str> {
+    text.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(parse_card)
+        .collect()
+}
+
 fn previous_rank(rank:&Rank) -> Option<Rank> {
     match *rank {
         Rank::Three    => None,
@@ -1,5 +1,5 @@
 use rand;
-use rand::Rng;
+use rand::{Isaac64Rng, Rng, SeedableRng};
 
 use cards::types::*;
 use cards::card::{Card, PlayerCard};
@@ -72,8 +72,44 @@ impl Deck {
         rng.shuffle(&mut self.0)
     }
     
+    /// the cards in the deck, top card last
+    pub fn get_cards(&self) -> Vec<PlayerCard> {
+        self.0.clone()
+    }
+
+    /// rearrange the cards in an order fixed by `seed`
+    ///
+    /// the generator and the draws are named exactly rather than
+    /// left to the platform, so a seed deals the same cards on
+    /// 32 and 64 bit targets, including wasm
+    pub fn shuffle_seeded(&mut self, seed: u64) {
+        let mut rng: Isaac64Rng = SeedableRng::from_seed(&[seed][..]);
+
+        for i in (1..self.0.len()).rev() {
+            let j = draw_below(&mut rng, i as u64 + 1) as usize;
+            self.0.swap(i, j);
+        }
+    }
+
+    /// the deck with the jokers taken out
+    pub fn without_jokers(&self) -> Deck {
+        Deck(self.0.iter().filter(|c| !matches!(**c, PlayerCard::Joker(_))).cloned().collect())
+    }
+
     /// number of cards in the deck
     pub fn count(&self) -> usize {
         self.0.len()
     }
 }
+
+// a number in 0..bound, drawing again rather than favouring low numbers
+fn draw_below(rng: &mut Isaac64Rng, bound: u64) -> u64 {
+    let zone = u64::MAX - u64::MAX % bound;
+
+    loop {
+        let n = rng.next_u64();
+        if n < zone {
+            return n % bound;
+        }
+    }
+}
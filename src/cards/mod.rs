@@ -6,3 +6,5 @@ pub mod types;
 ///! A deck of cards
 pub mod deck;
 
+///! Showing cards in different styles and languages
+pub mod render;
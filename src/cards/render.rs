@@ -0,0 +1,134 @@
+use cards::card::{Card, PlayerCard};
+use cards::types::{Rank, Suit};
+
+/// a language for card names
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Language {
+    /// "Three of Clubs"
+    English,
+    /// "Tres ng Trebol"
+    Filipino,
+    /// "梅花3"
+    Chinese
+}
+
+/// how to show a card
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Style {
+    /// plain text that can be parsed back, such as "3C" or "*10H"
+    Ascii,
+    /// suit glyph and rank, such as "♣3" - the same as `Display`
+    Unicode,
+    /// a single character from the playing cards block, such as "🃓"
+    PlayingCard,
+    /// the card's full name
+    Name(Language)
+}
+
+/// show a card in a style
+///
+/// a joker played as a card is marked as one in every style
+pub fn render_card(card: &PlayerCard, style: Style) -> String {
+    match *card {
+        PlayerCard::Card(c) => render(c, style),
+        PlayerCard::Wildcard(c) => match style {
+            Style::Ascii => format!("*{}", render(c, style)),
+            Style::Name(language) => format!("{} ({})", render(c, style), joker_name(language)),
+            _ => format!("{}*", render(c, style))
+        },
+        PlayerCard::Joker(n) => match style {
+            // numbered so that the card can be parsed back
+            Style::Ascii if n > 0 => format!("JK{}", n),
+            Style::Ascii => "JK".to_string(),
+            Style::Unicode => "🃏".to_string(),
+            Style::PlayingCard => '\u{1F0CF}'.to_string(),
+            Style::Name(language) => joker_name(language).to_string()
+        }
+    }
+}
+
+/// show several cards, separated by spaces
+pub fn render_cards(cards: &[PlayerCard], style: Style) -> String {
+    cards.iter().map(|c| render_card(c, style)).collect::<Vec<String>>().join(" ")
+}
+
+/// the name of a rank, such as "Three" or "Tres"
+pub fn rank_name(rank: Rank, language: Language) -> &'static str {
+    let names = match language {
+        Language::English => ["Three", "Four", "Five", "Six", "Seven", "Eight", "Nine",
+                              "Ten", "Jack", "Queen", "King", "Ace", "Two"],
+        Language::Filipino => ["Tres", "Kuwatro", "Singko", "Sais", "Siyete", "Otso", "Nuwebe",
+                               "Diyes", "Sota", "Reyna", "Hari", "Alas", "Dos"],
+        Language::Chinese => ["3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K", "A", "2"]
+    };
+
+    names[rank as usize]
+}
+
+/// the name of a suit, such as "Clubs" or "Trebol"
+pub fn suit_name(suit: Suit, language: Language) -> &'static str {
+    match (language, suit) {
+        (Language::English, Suit::Clubs) => "Clubs",
+        (Language::English, Suit::Hearts) => "Hearts",
+        (Language::English, Suit::Diamonds) => "Diamonds",
+        (Language::English, Suit::Spades) => "Spades",
+        (Language::Filipino, Suit::Clubs) => "Trebol",
+        (Language::Filipino, Suit::Hearts) => "Puso",
+        (Language::Filipino, Suit::Diamonds) => "Diyamante",
+        (Language::Filipino, Suit::Spades) => "Espada",
+        (Language::Chinese, Suit::Clubs) => "梅花",
+        (Language::Chinese, Suit::Hearts) => "红心",
+        (Language::Chinese, Suit::Diamonds) => "方块",
+        (Language::Chinese, Suit::Spades) => "黑桃"
+    }
+}
+
+fn render(card: Card, style: Style) -> String {
+    match style {
+        Style::Ascii => format!("{}{}", card.rank, suit_letter(card.suit)),
+        Style::Unicode => format!("{}", card),
+        Style::PlayingCard => playing_card(card).to_string(),
+        Style::Name(Language::English) => format!("{} of {}", rank_name(card.rank, Language::English), suit_name(card.suit, Language::English)),
+        Style::Name(Language::Filipino) => format!("{} ng {}", rank_name(card.rank, Language::Filipino), suit_name(card.suit, Language::Filipino)),
+        Style::Name(Language::Chinese) => format!("{}{}", suit_name(card.suit, Language::Chinese), rank_name(card.rank, Language::Chinese))
+    }
+}
+
+fn suit_letter(suit: Suit) -> char {
+    match suit {
+        Suit::Clubs => 'C',
+        Suit::Hearts => 'H',
+        Suit::Diamonds => 'D',
+        Suit::Spades => 'S'
+    }
+}
+
+// the playing cards block runs ace to king for each suit, with a
+// knight between the jack and queen that this game does not use
+fn playing_card(card: Card) -> char {
+    let base = match card.suit {
+        Suit::Spades => 0x1F0A0,
+        Suit::Hearts => 0x1F0B0,
+        Suit::Diamonds => 0x1F0C0,
+        Suit::Clubs => 0x1F0D0
+    };
+
+    let offset = match card.rank {
+        Rank::Ace => 1,
+        Rank::Two => 2,
+        Rank::Jack => 11,
+        Rank::Queen => 13,
+        Rank::King => 14,
+        rank => rank as u32 + 3
+    };
+
+    ::std::char::from_u32(base + offset).unwrap()
+}
+
+fn joker_name(language: Language) -> &'static str {
+    match language {
+        Language::English => "Joker",
+        Language::Filipino => "Joker",
+        Language::Chinese => "鬼牌"
+    }
+}
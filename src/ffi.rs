@@ -0,0 +1,335 @@
+//! a C interface to the engine
+//!
+//! a game is held behind an opaque `PdGame` handle which must be
+//! released with `pd_game_free`. every call returns one of the `PD_`
+//! status codes, with results written through out pointers.
+//!
+//! cards are passed as codes: `rank * 4 + suit` for a real card, with
+//! ranks from three (0) to two (12) and suits ordered clubs, hearts,
+//! diamonds, spades. a joker in a hand is `PD_JOKER`, and a joker played
+//! as a card is `PD_WILDCARD` plus the code of the card it stands for.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+use cards::card::{Card, PlayerCard};
+use cards::types::{Rank, Suit};
+use game::errors;
+use game::game::{Game, GameDefinition};
+use game::rules::Rules;
+
+/// success
+pub const PD_OK: i32 = 0;
+/// a required pointer was null
+pub const PD_ERR_NULL_POINTER: i32 = 1;
+/// the cards do not make a valid move
+pub const PD_ERR_INVALID_MOVE: i32 = 2;
+/// no player with that id is in the game
+pub const PD_ERR_INVALID_PLAYER: i32 = 3;
+/// the player does not hold the cards
+pub const PD_ERR_CARDS_NOT_HELD: i32 = 4;
+/// the move cannot be played now - out of turn, or too low
+pub const PD_ERR_MOVE_NOT_ALLOWED: i32 = 5;
+/// a card code is out of range
+pub const PD_ERR_INVALID_CARD: i32 = 6;
+/// the output buffer is too small - the needed length is still written
+pub const PD_ERR_BUFFER_TOO_SMALL: i32 = 7;
+/// the game could not be dealt
+pub const PD_ERR_SETUP_FAILED: i32 = 8;
+/// the game was won on the deal, so no cards can be played
+pub const PD_ERR_WON_ON_DEAL: i32 = 9;
+/// the player must play their highest single
+pub const PD_ERR_HIGHEST_SINGLE_REQUIRED: i32 = 10;
+/// the player must announce their last card
+pub const PD_ERR_LAST_CARD_NOT_ANNOUNCED: i32 = 11;
+/// a last card was announced for a move that does not leave one
+pub const PD_ERR_LAST_CARD_WRONGLY_ANNOUNCED: i32 = 12;
+/// any other error from the engine
+pub const PD_ERR_OTHER: i32 = 99;
+
+/// card code for a joker held in a hand
+pub const PD_JOKER: u32 = 52;
+/// added to a card code to play a joker as that card
+pub const PD_WILDCARD: u32 = 64;
+
+const RANKS: [Rank; 13] = [Rank::Three, Rank::Four, Rank::Five, Rank::Six,
+    Rank::Seven, Rank::Eight, Rank::Nine, Rank::Ten, Rank::Jack,
+    Rank::Queen, Rank::King, Rank::Ace, Rank::Two];
+
+const SUITS: [Suit; 4] = [Suit::Clubs, Suit::Hearts, Suit::Diamonds, Suit::Spades];
+
+/// a game in progress
+pub struct PdGame {
+    state: GameDefinition
+}
+
+/// deal a new game between `num_players` players, shuffled with `seed`
+///
+/// # Safety
+///
+/// `player_ids` must point to `num_players` ids and `out` must be writable
+#[no_mangle]
+pub unsafe extern "C" fn pd_game_new(player_ids: *const u64,
+                                     num_players: usize,
+                                     num_decks: usize,
+                                     seed: u64,
+                                     out: *mut *mut PdGame) -> i32 {
+    if player_ids.is_null() || out.is_null() {
+        return PD_ERR_NULL_POINTER;
+    }
+
+    if num_players == 0 || num_decks == 0 {
+        return PD_ERR_SETUP_FAILED;
+    }
+
+    guard(PD_ERR_SETUP_FAILED, || {
+        let ids = slice::from_raw_parts(player_ids, num_players).to_vec();
+
+        match Game::setup_seeded(ids, num_decks, Rules::default(), seed) {
+            Ok(state) => {
+                *out = Box::into_raw(Box::new(PdGame { state }));
+                PD_OK
+            },
+            Err(_) => PD_ERR_SETUP_FAILED
+        }
+    })
+}
+
+/// release a game - passing null does nothing
+///
+/// # Safety
+///
+/// `game` must have come from `pd_game_new` and not already been freed
+#[no_mangle]
+pub unsafe extern "C" fn pd_game_free(game: *mut PdGame) {
+    if !game.is_null() {
+        drop(Box::from_raw(game));
+    }
+}
+
+/// play `num_cards` card codes for a player - no cards is a pass
+///
+/// # Safety
+///
+/// `game` must be a live handle and `cards` must point to `num_cards` codes
+#[no_mangle]
+pub unsafe extern "C" fn pd_game_play(game: *mut PdGame,
+                                      player_id: u64,
+                                      cards: *const u32,
+                                      num_cards: usize) -> i32 {
+    pd_game_play_announcing(game, player_id, cards, num_cards, false)
+}
+
+/// play `num_cards` card codes for a player, saying whether they have
+/// announced the move leaves them with their last card
+///
+/// # Safety
+///
+/// `game` must be a live handle and `cards` must point to `num_cards` codes
+#[no_mangle]
+pub unsafe extern "C" fn pd_game_play_announcing(game: *mut PdGame,
+                                                 player_id: u64,
+                                                 cards: *const u32,
+                                                 num_cards: usize,
+                                                 last_card: bool) -> i32 {
+    if game.is_null() || (cards.is_null() && num_cards > 0) {
+        return PD_ERR_NULL_POINTER;
+    }
+
+    guard(PD_ERR_OTHER, || {
+        let game = &mut *game;
+        let codes = if num_cards == 0 { &[][..] } else { slice::from_raw_parts(cards, num_cards) };
+
+        let mut played = vec!();
+        for &code in codes {
+            match from_code(code, game.state.reversed) {
+                Some(card) => played.push(card),
+                None => return PD_ERR_INVALID_CARD
+            }
+        }
+
+        let result = Game::load(game.state.clone())
+            .and_then(|g| g.player_move_announcing(player_id, played, last_card));
+
+        match result {
+            Ok(state) => {
+                game.state = state;
+                PD_OK
+            },
+            Err(e) => error_code(e)
+        }
+    })
+}
+
+/// write a player's hand as card codes
+///
+/// # Safety
+///
+/// `game` must be a live handle, `out` must have room for `capacity`
+/// codes and `out_len` must be writable
+#[no_mangle]
+pub unsafe extern "C" fn pd_game_hand(game: *const PdGame,
+                                      player_id: u64,
+                                      out: *mut u32,
+                                      capacity: usize,
+                                      out_len: *mut usize) -> i32 {
+    if game.is_null() {
+        return PD_ERR_NULL_POINTER;
+    }
+
+    guard(PD_ERR_OTHER, || {
+        let player = match (*game).state.players.iter().find(|p| p.get_id() == player_id) {
+            Some(p) => p,
+            None => return PD_ERR_INVALID_PLAYER
+        };
+
+        let codes: Vec<u32> = player.get_hand().iter().map(to_code).collect();
+        write_out(&codes, out, capacity, out_len)
+    })
+}
+
+/// write the id of the player whose turn it is
+///
+/// # Safety
+///
+/// `game` must be a live handle and `out` must be writable
+#[no_mangle]
+pub unsafe extern "C" fn pd_game_next_player(game: *const PdGame, out: *mut u64) -> i32 {
+    if game.is_null() || out.is_null() {
+        return PD_ERR_NULL_POINTER;
+    }
+
+    guard(PD_ERR_OTHER, || {
+        match Game::load((*game).state.clone()) {
+            Ok(g) => match g.get_next_player() {
+                Some(p) => {
+                    *out = p.get_id();
+                    PD_OK
+                },
+                None => PD_ERR_INVALID_PLAYER
+            },
+            Err(e) => error_code(e)
+        }
+    })
+}
+
+/// write the cards of the move to beat - none after a pass or at the
+/// start of a round
+///
+/// # Safety
+///
+/// `game` must be a live handle, `out` must have room for `capacity`
+/// codes and `out_len` must be writable
+#[no_mangle]
+pub unsafe extern "C" fn pd_game_last_move(game: *const PdGame,
+                                           out: *mut u32,
+                                           capacity: usize,
+                                           out_len: *mut usize) -> i32 {
+    if game.is_null() {
+        return PD_ERR_NULL_POINTER;
+    }
+
+    guard(PD_ERR_OTHER, || {
+        let cards = (*game).state.round.get_last_move().get_cards();
+
+        let codes: Vec<u32> = cards.into_iter().map(|c| to_code(&PlayerCard::Card(c))).collect();
+        write_out(&codes, out, capacity, out_len)
+    })
+}
+
+/// write the ids of players who have gone out, in finishing order
+///
+/// # Safety
+///
+/// `game` must be a live handle, `out` must have room for `capacity`
+/// ids and `out_len` must be writable
+#[no_mangle]
+pub unsafe extern "C" fn pd_game_winners(game: *const PdGame,
+                                         out: *mut u64,
+                                         capacity: usize,
+                                         out_len: *mut usize) -> i32 {
+    if game.is_null() {
+        return PD_ERR_NULL_POINTER;
+    }
+
+    write_out(&(*game).state.winners, out, capacity, out_len)
+}
+
+/// whether the card order is currently reversed
+///
+/// # Safety
+///
+/// `game` must be a live handle
+#[no_mangle]
+pub unsafe extern "C" fn pd_game_is_reversed(game: *const PdGame) -> bool {
+    !game.is_null() && (*game).state.reversed
+}
+
+// the card a code stands for, oriented to the current card order
+fn from_code(code: u32, reversed: bool) -> Option<PlayerCard> {
+    let (wildcard, code) = if code >= PD_WILDCARD { (true, code - PD_WILDCARD) } else { (false, code) };
+
+    let rank = *RANKS.get((code / 4) as usize)?;
+    let suit = SUITS[(code % 4) as usize];
+    let card = Card::new(rank, suit, reversed);
+
+    if wildcard {
+        Some(PlayerCard::Wildcard(card))
+    } else {
+        Some(PlayerCard::Card(card))
+    }
+}
+
+fn to_code(card: &PlayerCard) -> u32 {
+    let code = |c: Card| {
+        let rank = RANKS.iter().position(|r| *r == c.rank).unwrap();
+        let suit = SUITS.iter().position(|s| *s == c.suit).unwrap();
+        (rank * 4 + suit) as u32
+    };
+
+    match *card {
+        PlayerCard::Joker(_) => PD_JOKER,
+        PlayerCard::Card(c) => code(c),
+        PlayerCard::Wildcard(c) => PD_WILDCARD + code(c)
+    }
+}
+
+// the status code for an error from the engine
+pub(crate) fn error_code(e: &'static str) -> i32 {
+    match e {
+        errors::INVALID_MOVE => PD_ERR_INVALID_MOVE,
+        errors::INVALID_PLAYER => PD_ERR_INVALID_PLAYER,
+        errors::CARDS_NOT_HELD => PD_ERR_CARDS_NOT_HELD,
+        errors::MOVE_NOT_ALLOWED => PD_ERR_MOVE_NOT_ALLOWED,
+        errors::WON_ON_DEAL => PD_ERR_WON_ON_DEAL,
+        errors::HIGHEST_SINGLE_REQUIRED => PD_ERR_HIGHEST_SINGLE_REQUIRED,
+        errors::LAST_CARD_NOT_ANNOUNCED => PD_ERR_LAST_CARD_NOT_ANNOUNCED,
+        errors::LAST_CARD_WRONGLY_ANNOUNCED => PD_ERR_LAST_CARD_WRONGLY_ANNOUNCED,
+        _ => PD_ERR_OTHER
+    }
+}
+
+// run a call, returning `failed` instead of letting a panic
+// unwind into C
+fn guard<F: FnOnce() -> i32>(failed: i32, call: F) -> i32 {
+    panic::catch_unwind(AssertUnwindSafe(call)).unwrap_or(failed)
+}
+
+unsafe fn write_out<T: Copy>(values: &[T], out: *mut T, capacity: usize, out_len: *mut usize) -> i32 {
+    if out_len.is_null() || (out.is_null() && capacity > 0) {
+        return PD_ERR_NULL_POINTER;
+    }
+
+    *out_len = values.len();
+
+    if values.len() > capacity {
+        return PD_ERR_BUFFER_TOO_SMALL;
+    }
+
+    if !values.is_empty() {
+        ptr::copy_nonoverlapping(values.as_ptr(), out, values.len());
+    }
+
+    PD_OK
+}
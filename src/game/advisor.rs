@@ -0,0 +1,196 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use cards::card::{Card, PlayerCard};
+use cards::types::Rank;
+use game::combinations;
+use game::player_move::{Move, TrickType, build_move};
+use game::rules::Variant;
+use game::tien_len::{self, Combination};
+
+/// a suggested play and why it was suggested
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hint {
+    /// cards to play - empty for a pass
+    pub cards: Vec<PlayerCard>,
+    /// higher is better
+    pub score: i64,
+    /// human readable explanations
+    pub reasons: Vec<String>
+}
+
+/// rank the legal moves for a hand in a variant, best first
+pub fn get_hints(hand: &[PlayerCard],
+                 last_move: Move,
+                 legal_moves: Vec<Vec<PlayerCard>>,
+                 variant: Variant) -> Vec<Hint> {
+    let plays: Vec<(Vec<PlayerCard>, Move)> = legal_moves.iter()
+        .filter(|cards| !cards.is_empty())
+        .filter_map(|cards| variant.build_move(cards).map(|m| (cards.clone(), m)))
+        .collect();
+    let can_pass = legal_moves.iter().any(|cards| cards.is_empty());
+
+    let counts = rank_counts(hand);
+    let best_trick = match variant {
+        Variant::PusoyDos => best_five_card_trick(hand),
+        Variant::TienLen => None
+    };
+    let control = control_rank(hand);
+    let only_control = !plays.is_empty() && plays.iter().all(|(cards, _)| uses_rank(cards, control));
+
+    let mut hints: Vec<Hint> = plays.iter().map(|&(ref cards, m)| {
+        let mut score = 0;
+        let mut reasons = vec!();
+
+        if cards.len() == hand.len() {
+            score += 1000;
+            reasons.push("plays your last cards".to_string());
+        }
+
+        let top = m.get_top_card();
+        let is_lowest = plays.iter().all(|&(_, other)| !m.types_match(&other) || compare(other, m, variant) != Ordering::Less);
+        score -= strength(top, variant);
+
+        if last_move == Move::Pass {
+            score += 20 * cards.len() as i64;
+            if cards.len() > 1 {
+                reasons.push(format!("gets rid of {} cards at once", cards.len()));
+            }
+            if is_lowest {
+                reasons.push(format!("leads with your lowest {}", describe(m)));
+            }
+        } else if is_lowest {
+            reasons.push(format!("lowest {} that beats {}", describe(m), last_move.get_top_card()));
+        }
+
+        if !matches!(m, Move::FiveCardTrick(_)) {
+            for (rank, used) in rank_counts(cards) {
+                let held = counts[&rank];
+                if held > used {
+                    score -= 15;
+                    reasons.push(format!("splits your {} of {}s", group_name(held), rank));
+                }
+            }
+        }
+
+        if let Some((ref trick_cards, trick)) = best_trick {
+            if trick_cards.iter().all(|c| !cards.contains(c)) {
+                reasons.push(format!("keeps your {} intact", describe(trick)));
+            } else if *trick_cards != sorted(cards) {
+                score -= 20;
+                reasons.push(format!("breaks up your {}", describe(trick)));
+            }
+        }
+
+        if uses_rank(cards, control) {
+            if only_control && last_move != Move::Pass {
+                reasons.push(format!("only {}s can beat this", control));
+            } else {
+                score -= 30;
+                reasons.push(format!("uses a {} you may want later", control));
+            }
+        }
+
+        Hint { cards: cards.clone(), score, reasons }
+    }).collect();
+
+    if can_pass {
+        let reason = if plays.is_empty() {
+            format!("nothing in your hand beats {}", last_move.get_top_card())
+        } else {
+            "saves your cards for later".to_string()
+        };
+
+        hints.push(Hint { cards: vec!(), score: -60, reasons: vec!(reason) });
+    }
+
+    hints.sort_by_key(|h| -h.score);
+    hints
+}
+
+/// a description of a move such as "pair" or "full house"
+pub fn describe(m: Move) -> &'static str {
+    match m {
+        Move::Pass => "pass",
+        Move::Single(_) => "single",
+        Move::Pair(_, _) => "pair",
+        Move::Prial(_, _, _) => "prial",
+        Move::FiveCardTrick(t) => match t.trick_type {
+            TrickType::Straight => "straight",
+            TrickType::Flush => "flush",
+            TrickType::FullHouse => "full house",
+            TrickType::FourOfAKind => "four of a kind",
+            TrickType::StraightFlush => "straight flush",
+            TrickType::FiveOfAKind => "five of a kind"
+        },
+        Move::TienLen(p) => match p.get_combination() {
+            Combination::Sequence(_) => "sequence",
+            Combination::ConsecutivePairs(_) => "consecutive pairs",
+            _ => "four of a kind"
+        }
+    }
+}
+
+// position of a card in the current order, from 0 (lowest) to 51
+fn strength(card: Card, variant: Variant) -> i64 {
+    let suit = match variant {
+        Variant::PusoyDos => card.suit as i64,
+        Variant::TienLen => tien_len::suit_order(card.suit) as i64
+    };
+    let natural = card.rank as i64 * 4 + suit;
+    if card.reversed { 51 - natural } else { natural }
+}
+
+// order moves of the same kind as the variant does
+fn compare(a: Move, b: Move, variant: Variant) -> Ordering {
+    match variant {
+        Variant::PusoyDos => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        Variant::TienLen => tien_len::compare(&a.get_top_card(), &b.get_top_card())
+    }
+}
+
+// the rank that beats everything else - twos, or threes once reversed
+fn control_rank(hand: &[PlayerCard]) -> Rank {
+    let reversed = hand.iter().any(|c| match *c {
+        PlayerCard::Card(card) | PlayerCard::Wildcard(card) => card.reversed,
+        PlayerCard::Joker(_) => false
+    });
+
+    if reversed { Rank::Three } else { Rank::Two }
+}
+
+fn uses_rank(cards: &[PlayerCard], rank: Rank) -> bool {
+    cards.iter().any(|c| c.to_card().rank == rank)
+}
+
+fn rank_counts(cards: &[PlayerCard]) -> HashMap<Rank, usize> {
+    cards.iter()
+        .filter(|c| matches!(**c, PlayerCard::Card(_)))
+        .fold(HashMap::new(), |mut acc, c| {
+            *acc.entry(c.to_card().rank).or_insert(0) += 1;
+            acc
+        })
+}
+
+fn group_name(count: usize) -> &'static str {
+    match count {
+        2 => "pair",
+        3 => "prial",
+        _ => "set"
+    }
+}
+
+fn best_five_card_trick(hand: &[PlayerCard]) -> Option<(Vec<PlayerCard>, Move)> {
+    combinations::get_five_card_tricks(hand).into_iter()
+        .filter_map(|cards| build_move(cards.clone()).map(|m| (sorted(&cards), m)))
+        .fold(None, |best: Option<(Vec<PlayerCard>, Move)>, (cards, m)| match best {
+            Some((_, b)) if b >= m => best,
+            _ => Some((cards, m))
+        })
+}
+
+fn sorted(cards: &[PlayerCard]) -> Vec<PlayerCard> {
+    let mut c = cards.to_vec();
+    c.sort();
+    c
+}
@@ -0,0 +1,313 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use cards::card::{Card, PlayerCard};
+use cards::types::{Rank, Suit};
+use game::player_move::{Move, build_move};
+use game::tien_len::{self, TienLenMove};
+
+const RANKS: [Rank; 13] = [Rank::Three, Rank::Four, Rank::Five, Rank::Six,
+    Rank::Seven, Rank::Eight, Rank::Nine, Rank::Ten, Rank::Jack,
+    Rank::Queen, Rank::King, Rank::Ace, Rank::Two];
+
+const SUITS: [Suit; 4] = [Suit::Clubs, Suit::Hearts, Suit::Diamonds, Suit::Spades];
+
+/// every valid move that can be made from a hand
+///
+/// jokers are not expanded into wildcards - only the
+/// real cards in the hand are combined
+pub fn get_all(hand: &[PlayerCard]) -> Vec<Vec<PlayerCard>> {
+    let mut moves = get_singles(hand);
+    moves.extend(get_pairs(hand));
+    moves.extend(get_prials(hand));
+    moves.extend(get_five_card_tricks(hand));
+    moves
+}
+
+/// every single card in a hand
+pub fn get_singles(hand: &[PlayerCard]) -> Vec<Vec<PlayerCard>> {
+    unique(real_cards(hand).into_iter().map(|c| vec!(c)).collect())
+}
+
+/// every pair in a hand
+pub fn get_pairs(hand: &[PlayerCard]) -> Vec<Vec<PlayerCard>> {
+    of_a_kind(hand, 2)
+}
+
+/// every prial in a hand
+pub fn get_prials(hand: &[PlayerCard]) -> Vec<Vec<PlayerCard>> {
+    of_a_kind(hand, 3)
+}
+
+/// every five card trick in a hand
+pub fn get_five_card_tricks(hand: &[PlayerCard]) -> Vec<Vec<PlayerCard>> {
+    let cards = real_cards(hand);
+    let by_rank = group_by_rank(&cards);
+    let mut candidates = vec!();
+
+    // straights
+    for window in RANKS.windows(5) {
+        let groups: Vec<Vec<PlayerCard>> = window.iter()
+            .map(|r| by_rank.get(r).cloned().unwrap_or_default())
+            .collect();
+
+        if groups.iter().all(|g| !g.is_empty()) {
+            candidates.extend(product(&groups));
+        }
+    }
+
+    // flushes
+    for suit in SUITS.iter() {
+        let suited: Vec<PlayerCard> = cards.iter()
+            .filter(|c| c.to_card().suit == *suit)
+            .cloned()
+            .collect();
+        candidates.extend(choose(&suited, 5));
+    }
+
+    for (rank, group) in &by_rank {
+        // full houses
+        for three in choose(group, 3) {
+            for (other, pair_group) in &by_rank {
+                if other == rank {
+                    continue;
+                }
+                for two in choose(pair_group, 2) {
+                    let mut trick = three.clone();
+                    trick.extend(two);
+                    candidates.push(trick);
+                }
+            }
+        }
+
+        // four of a kind
+        for four in choose(group, 4) {
+            for kicker in cards.iter().filter(|c| c.to_card().rank != *rank) {
+                let mut trick = four.clone();
+                trick.push(*kicker);
+                candidates.push(trick);
+            }
+        }
+
+        // five of a kind
+        candidates.extend(choose(group, 5));
+    }
+
+    unique(candidates.into_iter().filter(|c| build_move(c.clone()).is_some()).collect())
+}
+
+fn of_a_kind(hand: &[PlayerCard], n: usize) -> Vec<Vec<PlayerCard>> {
+    let cards = real_cards(hand);
+    let mut moves = vec!();
+
+    for group in group_by_rank(&cards).values() {
+        moves.extend(choose(group, n));
+    }
+
+    unique(moves)
+}
+
+fn real_cards(hand: &[PlayerCard]) -> Vec<PlayerCard> {
+    hand.iter()
+        .filter(|c| matches!(**c, PlayerCard::Card(_)))
+        .cloned()
+        .collect()
+}
+
+fn group_by_rank(cards: &[PlayerCard]) -> BTreeMap<Rank, Vec<PlayerCard>> {
+    let mut groups = BTreeMap::new();
+
+    for card in cards {
+        groups.entry(card.to_card().rank).or_insert_with(Vec::new).push(*card);
+    }
+
+    groups
+}
+
+/// all ways of choosing `k` cards from `cards`
+pub fn choose(cards: &[PlayerCard], k: usize) -> Vec<Vec<PlayerCard>> {
+    if k == 0 {
+        return vec!(vec!());
+    }
+
+    if cards.len() < k {
+        return vec!();
+    }
+
+    let mut chosen = vec!();
+
+    for (i, card) in cards.iter().enumerate() {
+        for mut rest in choose(&cards[i + 1..], k - 1) {
+            rest.insert(0, *card);
+            chosen.push(rest);
+        }
+    }
+
+    chosen
+}
+
+fn product(groups: &[Vec<PlayerCard>]) -> Vec<Vec<PlayerCard>> {
+    groups.iter().fold(vec!(vec!()), |acc, group| {
+        let mut next = vec!();
+        for partial in &acc {
+            for card in group {
+                let mut p: Vec<PlayerCard> = partial.clone();
+                p.push(*card);
+                next.push(p);
+            }
+        }
+        next
+    })
+}
+
+// with multiple decks the same set of cards can be chosen more than once
+fn unique(moves: Vec<Vec<PlayerCard>>) -> Vec<Vec<PlayerCard>> {
+    let mut seen = BTreeSet::new();
+
+    moves.into_iter().filter(|m| {
+        let mut key = m.clone();
+        key.sort();
+        seen.insert(key)
+    }).collect()
+}
+
+/// whether any move made from `cards` beats `target`, using
+/// any jokers as wildcards
+pub fn can_beat(cards: &[PlayerCard], target: Move) -> bool {
+    let reversed = target != Move::Pass && target.get_top_card().reversed;
+    let real = real_cards(cards);
+    let jokers = cards.len() - real.len();
+
+    let size = match target {
+        Move::Pass => return false,
+        Move::Single(_) => 1,
+        Move::Pair(_, _) => 2,
+        Move::Prial(_, _, _) => 3,
+        Move::FiveCardTrick(_) => {
+            let mut tricks = get_five_card_tricks(&real);
+            if jokers > 0 {
+                tricks.extend(get_wildcard_tricks(&real, jokers, reversed));
+            }
+
+            return tricks.into_iter()
+                .filter_map(build_move)
+                .any(|m| m > target);
+        },
+        Move::TienLen(play) => {
+            let target = TienLenMove::from(play);
+
+            return tien_len::get_all(cards).iter()
+                .filter_map(|m| TienLenMove::build(m))
+                .any(|m| m.beats(&target));
+        }
+    };
+
+    let by_rank = group_by_rank(&real);
+
+    // wildcards take the strongest suit of the rank
+    let suit = best_suit(reversed);
+
+    RANKS.iter().any(|rank| {
+        let mut group = by_rank.get(rank).cloned().unwrap_or_default();
+        group.sort_by(|a, b| b.to_card().partial_cmp(&a.to_card()).unwrap());
+
+        (0..jokers.min(size) + 1).any(|wildcards| {
+            if group.len() + wildcards < size {
+                return false;
+            }
+
+            let mut attempt = group[..size - wildcards].to_vec();
+            while attempt.len() < size {
+                attempt.push(PlayerCard::Wildcard(Card::new(*rank, suit, reversed)));
+            }
+
+            match build_move(attempt) {
+                Some(m) => m > target,
+                None => false
+            }
+        })
+    })
+}
+
+// the strongest five card tricks of each shape that can be made by
+// filling gaps with up to `jokers` wildcards
+fn get_wildcard_tricks(real: &[PlayerCard], jokers: usize, reversed: bool) -> Vec<Vec<PlayerCard>> {
+    let wildcard = |rank: Rank, suit: Suit| PlayerCard::Wildcard(Card::new(rank, suit, reversed));
+    let strongest_first = |cards: &mut Vec<PlayerCard>| {
+        cards.sort_by(|a, b| b.to_card().partial_cmp(&a.to_card()).unwrap());
+    };
+    let top_suit = best_suit(reversed);
+
+    let mut by_rank = group_by_rank(real);
+    for group in by_rank.values_mut() {
+        strongest_first(group);
+    }
+
+    // take up to `n` real cards of a rank and fill the rest with wildcards
+    let of_rank = |rank: Rank, n: usize| {
+        let mut set: Vec<PlayerCard> = by_rank.get(&rank).map_or(vec!(), |g| g.iter().take(n).cloned().collect());
+        while set.len() < n {
+            set.push(wildcard(rank, top_suit));
+        }
+        set
+    };
+
+    let mut candidates = vec!();
+
+    for rank in RANKS.iter() {
+        candidates.push(of_rank(*rank, 5));
+
+        for other in RANKS.iter().filter(|r| *r != rank) {
+            let mut four = of_rank(*rank, 4);
+            four.extend(of_rank(*other, 1));
+            candidates.push(four);
+
+            let mut full_house = of_rank(*rank, 3);
+            full_house.extend(of_rank(*other, 2));
+            candidates.push(full_house);
+        }
+    }
+
+    for window in RANKS.windows(5) {
+        let suits: Vec<Option<Suit>> = SUITS.iter().map(|s| Some(*s)).chain(Some(None)).collect();
+
+        for suit in suits {
+            let trick = window.iter().map(|rank| {
+                let held = by_rank.get(rank).and_then(|g| {
+                    g.iter().find(|c| suit.is_none_or(|s| c.to_card().suit == s)).cloned()
+                });
+                held.unwrap_or_else(|| wildcard(*rank, suit.unwrap_or(top_suit)))
+            }).collect();
+            candidates.push(trick);
+        }
+    }
+
+    for suit in SUITS.iter() {
+        let mut flush: Vec<PlayerCard> = real.iter().filter(|c| c.to_card().suit == *suit).cloned().collect();
+        strongest_first(&mut flush);
+        flush.truncate(5);
+
+        let mut ranks: Vec<Rank> = RANKS.to_vec();
+        if !reversed {
+            ranks.reverse();
+        }
+
+        for rank in ranks {
+            if flush.len() == 5 {
+                break;
+            }
+            if !flush.iter().any(|c| c.to_card().rank == rank) {
+                flush.push(wildcard(rank, *suit));
+            }
+        }
+        candidates.push(flush);
+    }
+
+    candidates.into_iter()
+        .filter(|c| c.iter().filter(|card| matches!(**card, PlayerCard::Wildcard(_))).count() <= jokers)
+        .collect()
+}
+
+// the strongest suit in the current card order
+fn best_suit(reversed: bool) -> Suit {
+    if reversed { Suit::Clubs } else { Suit::Spades }
+}
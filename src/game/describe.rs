@@ -0,0 +1,70 @@
+use cards::card::{Card, PlayerCard};
+use cards::render::{self, Language, Style};
+use cards::types::Rank;
+use game::player_move::{Move, Trick, TrickType};
+use game::tien_len::{Combination, TienLenPlay};
+
+/// describe a move in words, such as "Pair of 6s" or
+/// "Full house, Kings over 4s"
+pub fn describe_move(player_move: Move) -> String {
+    match player_move {
+        Move::Pass => "Pass".to_string(),
+        Move::Single(c) => render::render_card(&PlayerCard::Card(c), Style::Name(Language::English)),
+        Move::Pair(a, _) => format!("Pair of {}", plural(a.rank)),
+        Move::Prial(a, _, _) => format!("Prial of {}", plural(a.rank)),
+        Move::FiveCardTrick(t) => describe_trick(t),
+        Move::TienLen(p) => describe_tien_len(p)
+    }
+}
+
+/// describe a Tiến lên play in words, such as "Sequence of 5, 9 high"
+pub fn describe_tien_len(play: TienLenPlay) -> String {
+    let top = play.get_top_card();
+
+    match play.get_combination() {
+        Combination::Sequence(n) => format!("Sequence of {}, {} high", n, singular(top.rank)),
+        Combination::ConsecutivePairs(n) => format!("{} consecutive pairs, {} high", n, singular(top.rank)),
+        _ => format!("Four of a kind, {}", plural(top.rank))
+    }
+}
+
+/// describe a five card trick in words, such as "Flush, Ace high"
+pub fn describe_trick(trick: Trick) -> String {
+    let top = Move::FiveCardTrick(trick).get_top_card();
+
+    match trick.trick_type {
+        TrickType::Straight => format!("Straight, {} high", singular(top.rank)),
+        TrickType::Flush => format!("Flush, {} high", singular(top.rank)),
+        TrickType::StraightFlush => format!("Straight flush, {} high", singular(top.rank)),
+        TrickType::FullHouse => {
+            let three = most_common(&trick.cards);
+            let two = trick.cards.iter().map(|c| c.rank).find(|r| *r != three).unwrap();
+            format!("Full house, {} over {}", plural(three), plural(two))
+        },
+        TrickType::FourOfAKind => format!("Four of a kind, {}", plural(most_common(&trick.cards))),
+        TrickType::FiveOfAKind => format!("Five of a kind, {}", plural(top.rank))
+    }
+}
+
+/// show the cards of a move in a style - a pass has no cards
+pub fn render_move(player_move: Move, style: Style) -> String {
+    let cards: Vec<PlayerCard> = player_move.get_cards().into_iter().map(PlayerCard::Card).collect();
+    render::render_cards(&cards, style)
+}
+
+fn most_common(cards: &[Card]) -> Rank {
+    let count = |rank: Rank| cards.iter().filter(|c| c.rank == rank).count();
+    cards.iter().map(|c| c.rank).max_by_key(|r| count(*r)).unwrap()
+}
+
+// face cards by name and the rest as numbers, such as "Ace" or "9"
+fn singular(rank: Rank) -> String {
+    match rank {
+        Rank::Jack | Rank::Queen | Rank::King | Rank::Ace => render::rank_name(rank, Language::English).to_string(),
+        _ => format!("{}", rank)
+    }
+}
+
+fn plural(rank: Rank) -> String {
+    format!("{}s", singular(rank))
+}
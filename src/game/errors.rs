@@ -0,0 +1,26 @@
+// shared so that bindings can tell errors apart without
+// copying the messages
+
+/// the game was decided before any card was played
+pub const WON_ON_DEAL: &str = "The game was won on the deal";
+
+/// the cards do not make a move
+pub const INVALID_MOVE: &str = "Invalid move!";
+
+/// no player with that id is in the game
+pub const INVALID_PLAYER: &str = "Invalid player!";
+
+/// the player does not hold the cards
+pub const CARDS_NOT_HELD: &str = "Cannot play cards you do not have";
+
+/// the move cannot be played now - out of turn, or too low
+pub const MOVE_NOT_ALLOWED: &str = "move was invalid";
+
+/// a lower single was played when the next player has one card
+pub const HIGHEST_SINGLE_REQUIRED: &str = "You must play your highest single when the next player has one card";
+
+/// a player went down to one card without saying so
+pub const LAST_CARD_NOT_ANNOUNCED: &str = "You must announce your last card";
+
+/// a last card was announced for a move that does not leave one
+pub const LAST_CARD_WRONGLY_ANNOUNCED: &str = "You can only announce your last card when playing down to one card";
@@ -0,0 +1,181 @@
+use sha2::{Digest, Sha256};
+
+use cards::card::PlayerCard;
+use cards::deck::Deck;
+use game::game::{Game, GameDefinition};
+use game::rules::{Rules, Variant};
+
+/// a deal that players can check was not rigged
+///
+/// the dealer shuffles with a secret seed and publishes a commitment
+/// to the shuffled deck before play. players then add their own
+/// entropy, which reshuffles the committed deck in a way the dealer
+/// could not predict. once the game is over the dealer reveals the
+/// seed and salt so anyone can check the commitment and repeat the deal
+#[derive(Clone, Debug)]
+pub struct FairDeal {
+    num_decks: usize,
+    seed: u64,
+    salt: String,
+    players: Vec<u64>,
+    entropy: Vec<(u64, String)>,
+    variant: Variant,
+    dealt: bool
+}
+
+/// everything needed to check a deal once the game is over
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DealReveal {
+    /// decks shuffled together
+    pub num_decks: usize,
+    /// the dealer's secret seed
+    pub seed: u64,
+    /// the salt hashed with the deck
+    pub salt: String,
+    /// players in seat order
+    pub players: Vec<u64>,
+    /// entropy added by each player
+    pub entropy: Vec<(u64, String)>,
+    /// the game dealt, which decides the cards played with
+    #[serde(default)]
+    pub variant: Variant
+}
+
+impl FairDeal {
+
+    /// shuffle for `players` with the dealer's secret `seed` and `salt`
+    pub fn new(players: Vec<u64>, num_decks: usize, seed: u64, salt: &str) -> FairDeal {
+        FairDeal {
+            num_decks,
+            seed,
+            salt: salt.to_string(),
+            players,
+            entropy: vec!(),
+            variant: Variant::default(),
+            dealt: false
+        }
+    }
+
+    /// the hash to publish before any entropy is added
+    pub fn get_commitment(&self) -> String {
+        commit(&self.salt, &dealer_deck(self.num_decks, self.seed))
+    }
+
+    /// mix a player's entropy into the deal - each player may add
+    /// entropy once, before the cards are dealt
+    pub fn add_entropy(&self, player_id: u64, entropy: &str) -> Result<FairDeal, &'static str> {
+        if self.dealt {
+            return Err("The cards have already been dealt");
+        }
+
+        if !self.players.contains(&player_id) {
+            return Err("Invalid player!");
+        }
+
+        if self.entropy.iter().any(|&(p, _)| p == player_id) {
+            return Err("This player has already added entropy");
+        }
+
+        let mut deal = self.clone();
+        deal.entropy.push((player_id, entropy.to_string()));
+        Ok(deal)
+    }
+
+    /// deal the cards, after which no more entropy can be added
+    pub fn deal(&self, rules: Rules) -> Result<(FairDeal, GameDefinition), &'static str> {
+        let deck = final_deck(self.num_decks, self.seed, &self.entropy);
+        let variant = rules.variant;
+        let game = Game::deal(self.players.clone(), deck, rules)?;
+
+        let mut deal = self.clone();
+        deal.variant = variant;
+        deal.dealt = true;
+        Ok((deal, game))
+    }
+
+    /// the secrets to publish once the game is over
+    pub fn reveal(&self) -> Result<DealReveal, &'static str> {
+        if !self.dealt {
+            return Err("The cards have not been dealt");
+        }
+
+        Ok(DealReveal {
+            num_decks: self.num_decks,
+            seed: self.seed,
+            salt: self.salt.clone(),
+            players: self.players.clone(),
+            entropy: self.entropy.clone(),
+            variant: self.variant
+        })
+    }
+}
+
+impl DealReveal {
+
+    /// check the revealed seed against the published commitment,
+    /// returning the hands that should have been dealt
+    pub fn verify(&self, commitment: &str) -> Result<Vec<Vec<PlayerCard>>, &'static str> {
+        if commit(&self.salt, &dealer_deck(self.num_decks, self.seed)) != commitment {
+            return Err("The deal does not match the commitment");
+        }
+
+        let deck = final_deck(self.num_decks, self.seed, &self.entropy);
+        Ok(self.variant.deal(&deck, self.players.len()))
+    }
+
+    /// check a game was dealt from the revealed seed and entropy
+    pub fn verify_game(&self, commitment: &str, game: &GameDefinition) -> Result<(), &'static str> {
+        let hands = self.verify(commitment)?;
+
+        let dealt: Vec<Vec<PlayerCard>> = self.players.iter()
+            .map(|id| game.players.iter().find(|p| p.get_id() == *id).map(|p| p.get_hand()))
+            .collect::<Option<_>>()
+            .ok_or("Invalid player!")?;
+
+        if dealt != hands {
+            return Err("The hands do not match the deal");
+        }
+
+        Ok(())
+    }
+}
+
+// the deck as shuffled by the dealer alone
+fn dealer_deck(num_decks: usize, seed: u64) -> Deck {
+    let mut deck = Deck::combine((0..num_decks).map(|_| Deck::new()).collect());
+    deck.shuffle_seeded(seed);
+    deck
+}
+
+// the dealer's deck reshuffled with a seed every player had a hand in
+fn final_deck(num_decks: usize, seed: u64, entropy: &[(u64, String)]) -> Deck {
+    let mut sorted = entropy.to_vec();
+    sorted.sort();
+
+    let mut hasher = Sha256::new();
+    hasher.update(seed.to_le_bytes());
+    for (player, text) in &sorted {
+        hasher.update(player.to_le_bytes());
+        hasher.update((text.len() as u64).to_le_bytes());
+        hasher.update(text.as_bytes());
+    }
+
+    let digest = hasher.finalize();
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&digest[..8]);
+
+    let mut deck = dealer_deck(num_decks, seed);
+    deck.shuffle_seeded(u64::from_le_bytes(bytes));
+    deck
+}
+
+fn commit(salt: &str, deck: &Deck) -> String {
+    let cards = ::serde_json::to_vec(&deck.get_cards()).unwrap();
+
+    let mut hasher = Sha256::new();
+    hasher.update((salt.len() as u64).to_le_bytes());
+    hasher.update(salt.as_bytes());
+    hasher.update(cards);
+
+    hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect()
+}
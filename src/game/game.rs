@@ -3,10 +3,21 @@ use game::player::Player;
 use cards::deck::Deck;
 use cards::types::{Rank, Suit};
 use cards::card::{PlayerCard, Card};
-use game::player_move::{Move, TrickType, build_move};
+use game::player_move::{Move, TrickType};
+use game::combinations;
+use game::history::{Turn, PreviousState};
+use game::rules::{Rules, UndoPolicy, Variant};
+use game::tien_len::{self, TienLenMove};
+use game::advisor::{self, Hint};
+use game::validator::{self, Violation};
+use game::instant_win::{self, InstantWinClaim};
+use game::misdeal;
+use game::errors;
+use game::teams::{self, TeamOutcome};
+use game::tribute::Tribute;
 
 /// A definition of a game in progress
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GameDefinition{
     /// players
     pub players: Vec<Player>,
@@ -14,7 +25,93 @@ pub struct GameDefinition{
     pub round: Round,
     /// order of winners
     pub winners: Vec<u64>,
-    pub reversed: bool
+    pub reversed: bool,
+    /// every move made so far, oldest first
+    #[serde(default)]
+    pub history: Vec<Turn>,
+    /// optional rules chosen at setup
+    #[serde(default)]
+    pub rules: Rules,
+    /// what happened when the cards were dealt
+    #[serde(default)]
+    pub setup: SetupRecord
+}
+
+impl GameDefinition {
+    /// create a `GameDefinition` with no history, the default rules
+    /// and nothing recorded at setup
+    pub fn new(players: Vec<Player>, round: Round, winners: Vec<u64>, reversed: bool) -> GameDefinition {
+        GameDefinition{
+            players,
+            round,
+            winners,
+            reversed,
+            history: vec!(),
+            rules: Rules::default(),
+            setup: SetupRecord::default()
+        }
+    }
+
+    /// the same state with a move history
+    pub fn with_history(self, history: Vec<Turn>) -> GameDefinition {
+        GameDefinition{ history, ..self }
+    }
+
+    /// the same state with optional rules
+    pub fn with_rules(self, rules: Rules) -> GameDefinition {
+        GameDefinition{ rules, ..self }
+    }
+
+    /// the same state with a record of the deal
+    pub fn with_setup(self, setup: SetupRecord) -> GameDefinition {
+        GameDefinition{ setup, ..self }
+    }
+
+    /// decks in play - as recorded when the cards were dealt, or
+    /// the nearest whole number of the variant's decks to the cards
+    /// held and played
+    pub fn get_num_decks(&self) -> usize {
+        if self.setup.num_decks > 0 {
+            return self.setup.num_decks;
+        }
+
+        let held: usize = self.players.iter().map(|p| p.remaining_cards()).sum();
+        let played: usize = self.history.iter().map(|t| t.cards.len()).sum();
+        let deck_size = self.rules.variant.deck_size();
+
+        ((held + played + deck_size / 2) / deck_size).max(1)
+    }
+
+    /// check the state for consistency, returning every problem found
+    pub fn validate(&self) -> Vec<Violation> {
+        validator::validate(self)
+    }
+}
+
+/// What happened when the cards were dealt
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SetupRecord {
+    /// players who won with the hand they were dealt, in
+    /// finishing order - the game ends before any cards are played
+    pub instant_wins: Vec<InstantWinClaim>,
+    /// players who may ask for the cards to be dealt again
+    pub misdeals: Vec<u64>,
+    /// times the cards have been dealt again
+    pub redeals: usize,
+    /// decks used, if the game was dealt from fresh decks
+    pub num_decks: usize,
+    /// the seed the first deal was shuffled with, if any
+    pub seed: Option<u64>,
+    /// cards exchanged after the deal
+    pub tributes: Vec<Tribute>
+}
+
+/// The result of a finished game
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Outcome {
+    /// every player, from first to last
+    pub finishing_order: Vec<u64>
 }
 
 /// The Game module
@@ -22,27 +119,64 @@ pub struct Game {
     players: Vec<Player>,
     round: Round,
     winners: Vec<u64>,
-    reversed: bool
+    reversed: bool,
+    history: Vec<Turn>,
+    rules: Rules,
+    setup: SetupRecord
 }
 
 impl Game{
  
     /// create a new Game
     pub fn setup(player_ids:Vec<u64>, num_decks:usize) -> Result<GameDefinition, &'static str>{
-        let mut decks = vec!();
-
-        while decks.len() < num_decks {
-            decks.push(Deck::new());
-        }
+        Game::setup_with_rules(player_ids, num_decks, Rules::default())
+    }
 
-        let mut deck = Deck::combine(decks);
+    /// create a new Game with optional rules
+    pub fn setup_with_rules(player_ids:Vec<u64>, num_decks:usize, rules: Rules) -> Result<GameDefinition, &'static str>{
+        let mut deck = Game::get_deck(num_decks);
 
         deck.shuffle();
 
-        let player_count = player_ids.len();
+        let mut game = Game::deal(player_ids, deck, rules)?;
+        game.setup.num_decks = num_decks;
+        Ok(game)
+    }
+
+    /// create a new Game whose deal is fixed by `seed`
+    pub fn setup_seeded(player_ids:Vec<u64>, num_decks:usize, rules: Rules, seed: u64) -> Result<GameDefinition, &'static str>{
+        let mut deck = Game::get_deck(num_decks);
+
+        deck.shuffle_seeded(seed);
 
-        let dealt_cards = deck.deal(player_count);
-        let mut cards_iter = dealt_cards.iter();
+        let mut game = Game::deal(player_ids, deck, rules)?;
+        game.setup.num_decks = num_decks;
+        game.setup.seed = Some(seed);
+        Ok(game)
+    }
+
+    /// create a new Game by dealing out an already shuffled deck
+    pub fn deal(player_ids:Vec<u64>, deck: Deck, rules: Rules) -> Result<GameDefinition, &'static str>{
+        let hands = rules.variant.deal(&deck, player_ids.len());
+
+        Game::setup_with_hands(player_ids, hands, rules)
+    }
+
+    /// create a new Game where `hands[i]` is given to `player_ids[i]`
+    pub fn setup_with_hands(player_ids:Vec<u64>, hands: Vec<Vec<PlayerCard>>, rules: Rules) -> Result<GameDefinition, &'static str>{
+        if hands.len() != player_ids.len() {
+            return Err("There must be one hand for each player");
+        }
+
+        if !hands.iter().flatten().all(|c| rules.variant.uses_card(c)) {
+            return Err("Tiến lên is played without jokers");
+        }
+
+        if !rules.teams.is_empty() {
+            teams::check_teams(&rules.teams, &player_ids)?;
+        }
+
+        let mut cards_iter = hands.iter();
 
         let mut players = vec!();
 
@@ -52,14 +186,34 @@ impl Game{
             players.push(player.set_hand(hand.clone()));
         }
 
-        let next_player = Game::get_next(&players).unwrap().get_id();
+        let next_player = match Game::get_next(&players, &rules) {
+            Some(player) => player.get_id(),
+            None => return match rules.variant {
+                Variant::PusoyDos => Err("Nobody was dealt the three of clubs"),
+                Variant::TienLen => Err("Nobody was dealt the three of spades")
+            }
+        };
+
+        let mut instant_wins: Vec<InstantWinClaim> = players.iter().filter_map(|p| {
+            instant_win::find_instant_win(&p.get_hand(), &rules.instant_wins)
+                .map(|hand| InstantWinClaim{ player: p.get_id(), hand })
+        }).collect();
+        instant_wins.sort_by(|a, b| a.hand.partial_cmp(&b.hand).unwrap());
+
+        let misdeals = players.iter()
+            .filter(|p| misdeal::is_misdeal(&p.get_hand(), &rules.misdeals))
+            .map(|p| p.get_id())
+            .collect();
 
         Ok(
             GameDefinition{
                 players: players,
                 round: Game::get_empty_round(player_ids.clone(), next_player),
-                winners: vec!(),
-                reversed: false
+                winners: instant_wins.iter().map(|claim| claim.player).collect(),
+                reversed: false,
+                history: vec!(),
+                rules: rules,
+                setup: SetupRecord{ instant_wins, misdeals, ..SetupRecord::default() }
             }
         )
     }
@@ -72,40 +226,116 @@ impl Game{
                 players: game_definition.players,
                 round: game_definition.round, 
                 winners: game_definition.winners,
-                reversed: game_definition.reversed
+                reversed: game_definition.reversed,
+                history: game_definition.history,
+                rules: game_definition.rules,
+                setup: game_definition.setup
             }
         )
     }
 
+    /// load a game, refusing a `GameDefinition` that fails validation
+    pub fn load_validated(game_definition: GameDefinition) -> Result<Game, Vec<Violation>>{
+        let violations = game_definition.validate();
+
+        if !violations.is_empty() {
+            return Err(violations);
+        }
+
+        Ok(Game::load(game_definition).unwrap())
+    }
+
+    /// deal again at the request of a player whose hand is a misdeal,
+    /// before any cards have been played
+    ///
+    /// a seeded game is redealt from a seed worked out from the first,
+    /// so the same requests always give the same cards
+    pub fn request_redeal(&self, player_id: u64) -> Result<GameDefinition, &'static str> {
+        if !self.history.is_empty() || self.round.has_started() {
+            return Err("The game has already started");
+        }
+
+        if !self.setup.instant_wins.is_empty() {
+            return Err(errors::WON_ON_DEAL);
+        }
+
+        if !self.setup.misdeals.contains(&player_id) {
+            return Err("Only a player with a misdeal can ask for a redeal");
+        }
+
+        if self.setup.num_decks == 0 {
+            return Err("This game was not dealt from fresh decks");
+        }
+
+        let redeals = self.setup.redeals + 1;
+        let mut deck = Game::get_deck(self.setup.num_decks);
+
+        match self.setup.seed {
+            Some(seed) => deck.shuffle_seeded(misdeal::redeal_seed(seed, redeals)),
+            None => deck.shuffle()
+        }
+
+        let player_ids = self.players.iter().map(|p| p.get_id()).collect();
+        let mut game = Game::deal(player_ids, deck, self.rules.clone())?;
+        game.setup.num_decks = self.setup.num_decks;
+        game.setup.seed = self.setup.seed;
+        game.setup.redeals = redeals;
+        Ok(game)
+    }
+
     /// takes a player_id and a vec of cards for a move
     pub fn player_move(&self, player_id:u64, cards:Vec<PlayerCard>) -> Result<GameDefinition, &'static str> {
-       let p_move = build_move(cards.clone());
+        self.player_move_announcing(player_id, cards, false)
+    }
+
+    /// make a move, saying whether the player has announced it
+    /// leaves them with their last card
+    pub fn player_move_announcing(&self, player_id:u64, cards:Vec<PlayerCard>, last_card: bool) -> Result<GameDefinition, &'static str> {
+       if !self.setup.instant_wins.is_empty() {
+            return Err(errors::WON_ON_DEAL);
+       }
+
+       let p_move = self.rules.variant.build_move(&cards);
 
         // only allow valid hands
        if p_move == None {
-            return Err("Invalid move!");
+            return Err(errors::INVALID_MOVE);
        }
 
         // get player from id
        let current_player = self.get_current_player(player_id);
 
        if current_player == None {
-            return Err("Invalid player!");
+            return Err(errors::INVALID_PLAYER);
        }      
 
        let mut current_player = current_player.unwrap();
 
         // only allow cards in player hand
         if !self.player_has_card(&current_player, cards.clone()) {
-            return Err("Cannot play cards you do not have");
+            return Err(errors::CARDS_NOT_HELD);
        
         }
 
+        // a move out of turn is refused before the rules about
+        // what may be played are checked
+        if self.round.get_next_player() != player_id {
+            return Err(errors::MOVE_NOT_ALLOWED);
+        }
+
+        self.check_highest_single(player_id, &current_player, &cards)?;
+        self.check_last_card(&current_player, &cards, last_card)?;
+
        let mut players = self.players.clone();
        // don't remove me - I am useful for when
        // surfacing information about the validity of a move
        let valid_move;
-       let mut round = match self.round.play(player_id, p_move.unwrap()){
+       let played = match self.rules.variant {
+           Variant::PusoyDos => self.round.play(player_id, p_move.unwrap()),
+           Variant::TienLen => self.play_tien_len(player_id, &cards, p_move.unwrap())
+       };
+
+       let mut round = match played {
             Ok(r) => {
                 valid_move = true;
                 current_player = current_player.remove(&cards);
@@ -121,7 +351,7 @@ impl Game{
 
         let mut reversed = self.reversed;
 
-        if valid_move {
+        if valid_move && self.rules.variant == Variant::PusoyDos {
            // check for Four of a kind / Five of a kind and reverse cards
            // this means manually switching the cards in the players hands
            // and the last played hand on the round
@@ -149,21 +379,203 @@ impl Game{
 
        let winners = self.get_winners(&current_player);
 
+       if valid_move && self.rules.partner_takes_lead {
+           round = self.give_lead_to_partner(round, player_id, &cards, &players);
+       }
+
+       let previous = PreviousState{
+          hand: self.get_current_player(player_id).unwrap().get_hand(),
+          round: self.round.clone(),
+          winners: self.winners.clone(),
+          reversed: self.reversed
+       };
+
+       let mut history = self.history.clone();
+       history.push(Turn::new(player_id, cards).with_previous(previous).with_last_card(last_card));
+
        let game_def = GameDefinition{
           players: players.clone(),
           round: round,
           winners: winners,
-          reversed: reversed
+          reversed: reversed,
+          history: history,
+          rules: self.rules.clone(),
+          setup: self.setup.clone()
        };
 
        if valid_move {
            Ok(game_def)
        } else {
-           Err("move was invalid")
+           Err(errors::MOVE_NOT_ALLOWED)
        }
        
     }
   
+    /// the final placings, once the game is finished
+    pub fn get_outcome(&self) -> Option<Outcome> {
+        if !self.is_finished() {
+            return None;
+        }
+
+        let mut finishing_order = self.winners.clone();
+        for player in &self.players {
+            if !finishing_order.contains(&player.get_id()) {
+                finishing_order.push(player.get_id());
+            }
+        }
+
+        Some(Outcome{ finishing_order })
+    }
+
+    /// the placings and scores by team, once a partnership
+    /// game is finished
+    pub fn get_team_outcome(&self) -> Option<TeamOutcome> {
+        if self.rules.teams.is_empty() {
+            return None;
+        }
+
+        self.get_outcome().map(|outcome| teams::get_team_outcome(&self.rules.teams, &outcome))
+    }
+
+    /// check whether fewer than two players still hold cards, or
+    /// someone won with the hand they were dealt
+    pub fn is_finished(&self) -> bool {
+        !self.setup.instant_wins.is_empty()
+            || self.players.iter().filter(|p| p.remaining_cards() > 0).count() < 2
+    }
+
+    /// every move the player could legally make right now,
+    /// with an empty vec standing for a pass
+    ///
+    /// jokers are not offered as wildcards
+    pub fn get_legal_moves(&self, player_id: u64) -> Vec<Vec<PlayerCard>> {
+        let player = match self.get_current_player(player_id) {
+            Some(p) => p,
+            None => return vec!()
+        };
+
+        let mut candidates = vec!(vec!());
+
+        match self.rules.variant {
+            Variant::PusoyDos => candidates.extend(combinations::get_all(&player.get_hand())),
+            Variant::TienLen => candidates.extend(tien_len::get_all(&player.get_hand()))
+        }
+
+        candidates.into_iter().filter(|cards| {
+            if self.check_highest_single(player_id, &player, cards).is_err() {
+                return false;
+            }
+
+            match (self.rules.variant, self.rules.variant.build_move(cards)) {
+                (_, None) => false,
+                (Variant::PusoyDos, Some(m)) => self.round.play(player_id, m).is_ok(),
+                (Variant::TienLen, Some(m)) => self.play_tien_len(player_id, cards, m).is_ok()
+            }
+        }).collect()
+    }
+
+    /// suggested plays for the next player, best first
+    pub fn get_hints(&self) -> Vec<Hint> {
+        match self.get_next_player() {
+            Some(player) => advisor::get_hints(&player.get_hand(),
+                                               self.get_last_move(),
+                                               self.get_legal_moves(player.get_id()),
+                                               self.rules.variant),
+            None => vec!()
+        }
+    }
+
+    /// the move currently on the table
+    pub fn get_last_move(&self) -> Move {
+        self.round.get_last_move()
+    }
+
+    /// take back the last `count` moves on behalf of `player_id`,
+    /// as allowed by the game's `UndoPolicy`
+    pub fn undo(&self, player_id: u64, count: usize) -> Result<GameDefinition, &'static str> {
+        match self.rules.undo {
+            UndoPolicy::Disabled => return Err("Undo is not allowed in this game"),
+            UndoPolicy::BeforeNextAction => {
+                let last = self.history.last().map(|turn| turn.player);
+                if count != 1 || last != Some(player_id) {
+                    return Err("Only your own last move can be taken back");
+                }
+            },
+            UndoPolicy::Unrestricted => ()
+        }
+
+        if count == 0 {
+            return Err("Nothing to undo");
+        }
+
+        self.rewind(count)
+    }
+
+    /// the game as it was `count` moves ago
+    pub fn rewind(&self, count: usize) -> Result<GameDefinition, &'static str> {
+        if count > self.history.len() {
+            return Err("Nothing to undo");
+        }
+
+        let mut game_def = self.export();
+
+        for _ in 0..count {
+            game_def = Game::step_back(game_def)?;
+        }
+
+        Ok(game_def)
+    }
+
+    /// export the current state as a `GameDefinition`
+    pub fn export(&self) -> GameDefinition {
+        GameDefinition{
+            players: self.players.clone(),
+            round: self.round.clone(),
+            winners: self.winners.clone(),
+            reversed: self.reversed,
+            history: self.history.clone(),
+            rules: self.rules.clone(),
+            setup: self.setup.clone()
+        }
+    }
+
+    // restore the state from before the most recent move
+    fn step_back(mut game_def: GameDefinition) -> Result<GameDefinition, &'static str> {
+        let turn = game_def.history.pop().ok_or("Nothing to undo")?;
+        let previous = turn.previous.ok_or("This move cannot be taken back")?;
+        let mover = turn.player;
+
+        let players = game_def.players.iter().map(|p| {
+            if p.get_id() == mover {
+                p.set_hand(previous.hand.clone())
+            } else if game_def.reversed != previous.reversed {
+                p.reverse_hand()
+            } else {
+                p.clone()
+            }
+        }).collect();
+
+        Ok(GameDefinition{
+            players,
+            round: previous.round,
+            winners: previous.winners,
+            reversed: previous.reversed,
+            history: game_def.history,
+            rules: game_def.rules,
+            setup: game_def.setup
+        })
+    }
+
+    /// every move made so far, oldest first
+    pub fn get_history(&self) -> Vec<Turn> {
+        self.history.clone()
+    }
+
+    /// whether card order is currently reversed
+    pub fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
     /// get a player for querying information
     pub fn get_player(&self, id: u64) -> Option<Player> {
        self.get_current_player(id)
@@ -174,7 +586,7 @@ impl Game{
 
         let id = match self.round.has_started() {
             true => self.round.get_next_player(),
-            false => match Game::get_next(&self.players) {
+            false => match Game::get_next(&self.players, &self.rules) {
                 Some(player) => player.get_id(),
                 _ => self.round.get_next_player()
             }
@@ -184,11 +596,85 @@ impl Game{
         self.get_player(id)
     }
 
-    fn get_next(players: &Vec<Player>) -> Option<Player> {
-        let three_of_clubs = card!(Three, Clubs);
-        
+    fn get_deck(num_decks: usize) -> Deck {
+        let mut decks = vec!();
+
+        while decks.len() < num_decks {
+            decks.push(Deck::new());
+        }
+
+        Deck::combine(decks)
+    }
+
+    // the player seated after `player_id` among those still in play
+    fn get_player_after(&self, player_id: u64) -> Option<Player> {
+        let seats = self.round.export().players;
+        let index = seats.iter().position(|id| *id == player_id)?;
+
+        self.get_player(seats[(index + 1) % seats.len()])
+    }
+
+    // a single must be the player's highest when the next player
+    // is down to one card
+    fn check_highest_single(&self, player_id: u64, player: &Player, cards: &[PlayerCard]) -> Result<(), &'static str> {
+        if !self.rules.forced_highest_single || cards.len() != 1 {
+            return Ok(());
+        }
+
+        let next_has_one_card = self.get_player_after(player_id)
+            .map(|p| p.remaining_cards() == 1)
+            .unwrap_or(false);
+
+        if next_has_one_card && !self.is_highest_single(player, cards[0]) {
+            return Err(errors::HIGHEST_SINGLE_REQUIRED);
+        }
+
+        Ok(())
+    }
+
+    // a player must say when a move leaves them with one card, and
+    // only then
+    fn check_last_card(&self, player: &Player, cards: &[PlayerCard], last_card: bool) -> Result<(), &'static str> {
+        if !self.rules.announce_last_card {
+            return Ok(());
+        }
+
+        // a pass never changes the hand, so needs no announcement
+        let leaves_one_card = !cards.is_empty() && player.remaining_cards() == cards.len() + 1;
+
+        if leaves_one_card && !last_card {
+            return Err(errors::LAST_CARD_NOT_ANNOUNCED);
+        }
+
+        if !leaves_one_card && last_card {
+            return Err(errors::LAST_CARD_WRONGLY_ANNOUNCED);
+        }
+
+        Ok(())
+    }
+
+    // whether `card` is at least as high as every card in the hand -
+    // jokers are not counted, and a wildcard counts as the card it stands for
+    fn is_highest_single(&self, player: &Player, card: PlayerCard) -> bool {
+        let played = match card {
+            PlayerCard::Card(c) | PlayerCard::Wildcard(c) => c,
+            PlayerCard::Joker(_) => return false
+        };
+
+        player.get_hand().iter().all(|held| match *held {
+            PlayerCard::Card(c) => match self.rules.variant {
+                Variant::PusoyDos => c <= played,
+                Variant::TienLen => tien_len::compare(&c, &played) != ::std::cmp::Ordering::Greater
+            },
+            _ => true
+        })
+    }
+
+    fn get_next(players: &Vec<Player>, rules: &Rules) -> Option<Player> {
+        let first_card = Game::get_first_card(rules);
+
         for player in players {
-            if player.get_hand().contains(&three_of_clubs){
+            if player.get_hand().contains(&first_card){
                 return Some(player.clone());
             }
         }
@@ -196,6 +682,70 @@ impl Game{
         None
     }
 
+    // once nobody can beat the play of a player who has gone out,
+    // the lead goes to their partner rather than the next seat
+    fn give_lead_to_partner(&self, round: Round, player_id: u64, cards: &[PlayerCard], players: &[Player]) -> Round {
+        if round.get_last_move() != Move::Pass {
+            return round;
+        }
+
+        let leader = if !cards.is_empty() {
+            Some(player_id)
+        } else {
+            self.history.iter().rev().find(|turn| !turn.cards.is_empty()).map(|turn| turn.player)
+        };
+
+        let leader = match leader {
+            Some(id) => id,
+            None => return round
+        };
+
+        if players.iter().any(|p| p.get_id() == leader && p.remaining_cards() > 0) {
+            return round;
+        }
+
+        match teams::get_partner_in_play(&self.rules.teams, leader, &round.export().players) {
+            Some(partner) => round.set_current_player(partner),
+            None => round
+        }
+    }
+
+    // check a Tiến lên play against the cards on the table, then
+    // move the round on
+    fn play_tien_len(&self, player_id: u64, cards: &[PlayerCard], p_move: Move) -> Result<Round, Round> {
+        if !cards.is_empty() {
+            let new_move = match TienLenMove::build(cards) {
+                Some(m) => m,
+                None => return Err(self.round.clone())
+            };
+
+            if !self.round.has_started() && !cards.contains(&card!(Three, Spades)) {
+                return Err(self.round.clone());
+            }
+
+            if let Some(table) = self.get_tien_len_table() {
+                if !new_move.beats(&table) {
+                    return Err(self.round.clone());
+                }
+            }
+        }
+
+        self.round.play_unchecked(player_id, p_move)
+    }
+
+    // the Tiến lên play to beat, if there is one
+    fn get_tien_len_table(&self) -> Option<TienLenMove> {
+        TienLenMove::from_move(self.round.get_last_move())
+    }
+
+    // whoever holds this card starts the game
+    fn get_first_card(rules: &Rules) -> PlayerCard {
+        match rules.variant {
+            Variant::PusoyDos => card!(Three, Clubs),
+            Variant::TienLen => card!(Three, Spades)
+        }
+    }
+
     fn get_empty_round(player_ids:Vec<u64>, next:u64) -> Round {
         Round::new(player_ids, next, Move::Pass, 0, true)
     }
@@ -242,6 +792,9 @@ impl Game{
     }
     
     fn player_has_card(&self, player:&Player, cards:Vec<PlayerCard>) -> bool { 
+        let jokers = player.get_hand().iter().filter(|c| matches!(**c, PlayerCard::Joker(_))).count();
+        let mut wildcards = 0;
+
         for card in &cards {
             match *card {
                 PlayerCard::Card(_) => {
@@ -251,13 +804,13 @@ impl Game{
                         return false;
                     }
                 },
-                PlayerCard::Wildcard(_) => {
-                    // check player has a joker
-                },
-                PlayerCard::Joker(_) => { panic!("Joker is not a valid move - do you mean Wildcard?"); }
+                // each wildcard needs a joker in the hand
+                PlayerCard::Wildcard(_) => wildcards += 1,
+                // a joker must be played as the card it stands for
+                PlayerCard::Joker(_) => return false
             }
         }
 
-        true
+        wildcards <= jokers
     }
 }
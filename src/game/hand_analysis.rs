@@ -0,0 +1,158 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use cards::card::PlayerCard;
+use cards::types::Rank;
+use game::combinations;
+use game::player::Player;
+use game::player_move::{Move, TrickType, build_move};
+
+// the most five card tricks considered when splitting a hand
+const TRICK_POOL: usize = 100;
+
+/// one way of splitting a hand into plays
+#[derive(Clone, Debug, PartialEq)]
+pub struct Partition {
+    /// the cards of each play
+    pub plays: Vec<Vec<PlayerCard>>,
+    /// plays that are hard to beat - top rank cards, four of a
+    /// kind or better, and jokers held back as wildcards
+    pub control_plays: usize,
+    /// higher is better - fewer plays first, then more control
+    pub score: i64
+}
+
+impl Partition {
+    /// the plays as moves
+    pub fn get_moves(&self) -> Vec<Move> {
+        self.plays.iter().filter_map(|p| build_move(p.clone())).collect()
+    }
+}
+
+/// split a player's hand into singles, pairs, prials and five card
+/// tricks, returning up to `max` partitions, best first
+///
+/// jokers are left out of the plays and counted as control
+pub fn analyse_hand(player: &Player, max: usize) -> Vec<Partition> {
+    let hand = player.get_hand();
+    let cards: Vec<PlayerCard> = hand.iter()
+        .filter(|c| matches!(**c, PlayerCard::Card(_)))
+        .cloned()
+        .collect();
+    let jokers = hand.len() - cards.len();
+    let top = top_rank(&cards);
+
+    let mut tricks: Vec<(Vec<PlayerCard>, Move)> = combinations::get_five_card_tricks(&cards).into_iter()
+        .filter_map(|t| build_move(t.clone()).map(|m| (t, m)))
+        .collect();
+    tricks.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+    tricks.truncate(TRICK_POOL);
+
+    let trick_cards: Vec<Vec<PlayerCard>> = tricks.into_iter().map(|(t, _)| t).collect();
+    let mut partitions = vec!();
+    let mut seen = BTreeSet::new();
+
+    search(&cards, &trick_cards, 0, &mut vec!(), &mut |chosen: &[Vec<PlayerCard>], rest: &[PlayerCard]| {
+        let mut plays = chosen.to_vec();
+        plays.extend(group_remaining(rest));
+
+        let mut key: Vec<Vec<PlayerCard>> = plays.iter().map(|p| sorted(p)).collect();
+        key.sort();
+
+        if seen.insert(key) {
+            partitions.push(score(plays, jokers, top));
+        }
+    });
+
+    partitions.sort_by_key(|p| -p.score);
+    partitions.truncate(max);
+    partitions
+}
+
+// try every combination of up to two disjoint five card tricks
+fn search<F>(remaining: &[PlayerCard],
+             tricks: &[Vec<PlayerCard>],
+             start: usize,
+             chosen: &mut Vec<Vec<PlayerCard>>,
+             found: &mut F) where F: FnMut(&[Vec<PlayerCard>], &[PlayerCard]) {
+
+    found(chosen, remaining);
+
+    if chosen.len() == 2 {
+        return;
+    }
+
+    for (i, trick) in tricks.iter().enumerate().skip(start) {
+        if let Some(rest) = take(remaining, trick) {
+            chosen.push(trick.clone());
+            search(&rest, tricks, i + 1, chosen, found);
+            chosen.pop();
+        }
+    }
+}
+
+// remove cards from a hand, or None if any are missing
+fn take(hand: &[PlayerCard], cards: &[PlayerCard]) -> Option<Vec<PlayerCard>> {
+    let mut rest = hand.to_vec();
+
+    for card in cards {
+        let pos = rest.iter().position(|c| c == card)?;
+        rest.remove(pos);
+    }
+
+    Some(rest)
+}
+
+// split leftover cards into prials, pairs and singles by rank
+fn group_remaining(cards: &[PlayerCard]) -> Vec<Vec<PlayerCard>> {
+    let mut by_rank: BTreeMap<Rank, Vec<PlayerCard>> = BTreeMap::new();
+    for card in cards {
+        by_rank.entry(card.to_card().rank).or_default().push(*card);
+    }
+
+    let mut plays = vec!();
+
+    for group in by_rank.values() {
+        let mut rest = &group[..];
+        while !rest.is_empty() {
+            let size = match rest.len() {
+                4 => 2,
+                n if n >= 3 => 3,
+                n => n
+            };
+            plays.push(rest[..size].to_vec());
+            rest = &rest[size..];
+        }
+    }
+
+    plays
+}
+
+fn score(plays: Vec<Vec<PlayerCard>>, jokers: usize, top: Rank) -> Partition {
+    let control_plays = jokers + plays.iter().filter(|p| is_control(p, top)).count();
+    let score = control_plays as i64 * 10 - plays.len() as i64 * 100;
+
+    Partition { plays, control_plays, score }
+}
+
+fn is_control(play: &[PlayerCard], top: Rank) -> bool {
+    match build_move(play.to_vec()) {
+        Some(Move::FiveCardTrick(t)) => t.trick_type >= TrickType::FourOfAKind,
+        Some(Move::Pass) | None => false,
+        Some(_) => play.iter().any(|c| c.to_card().rank == top)
+    }
+}
+
+// twos are the top rank, unless the cards have been reversed
+fn top_rank(cards: &[PlayerCard]) -> Rank {
+    if cards.iter().any(|c| c.to_card().reversed) {
+        Rank::Three
+    } else {
+        Rank::Two
+    }
+}
+
+fn sorted(cards: &[PlayerCard]) -> Vec<PlayerCard> {
+    let mut c = cards.to_vec();
+    c.sort();
+    c
+}
@@ -0,0 +1,57 @@
+use cards::card::PlayerCard;
+use game::round::Round;
+
+/// a move that has been made in a game
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Turn {
+    /// who made the move
+    pub player: u64,
+    /// the cards as they were played - empty for a pass
+    pub cards: Vec<PlayerCard>,
+    /// the game as it was before the move, if recorded
+    #[serde(default)]
+    pub previous: Option<PreviousState>,
+    /// whether the player announced they were down to their last card
+    #[serde(default)]
+    pub last_card: bool
+}
+
+/// what a move changed, so that it can be taken back
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PreviousState {
+    /// the mover's hand
+    pub hand: Vec<PlayerCard>,
+    /// the round
+    pub round: Round,
+    /// order of winners
+    pub winners: Vec<u64>,
+    /// whether cards were reversed
+    pub reversed: bool
+}
+
+impl Turn {
+    /// create a new `Turn`
+    pub fn new(player: u64, cards: Vec<PlayerCard>) -> Turn {
+        Turn { player, cards, previous: None, last_card: false }
+    }
+
+    /// record the state before the move was made
+    pub fn with_previous(&self, previous: PreviousState) -> Turn {
+        Turn {
+            player: self.player,
+            cards: self.cards.clone(),
+            previous: Some(previous),
+            last_card: self.last_card
+        }
+    }
+
+    /// record whether the player announced their last card
+    pub fn with_last_card(&self, last_card: bool) -> Turn {
+        Turn { last_card, ..self.clone() }
+    }
+
+    /// whether the move was a pass
+    pub fn is_pass(&self) -> bool {
+        self.cards.is_empty()
+    }
+}
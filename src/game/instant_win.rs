@@ -0,0 +1,61 @@
+use std::collections::BTreeMap;
+
+use cards::card::PlayerCard;
+use cards::types::Rank;
+
+// cards in a hand the patterns are made for
+const HAND_SIZE: usize = 13;
+
+/// a dealt hand that wins the game before any cards are played,
+/// strongest first
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum InstantWin {
+    /// one card of every rank, from 3 to 2
+    Dragon,
+    /// all four 2s
+    FourTwos,
+    /// five pairs and a prial
+    FivePairsAndPrial,
+    /// six pairs - four of a kind counts as two
+    SixPairs
+}
+
+/// a player who won with their dealt hand
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InstantWinClaim {
+    /// who won
+    pub player: u64,
+    /// the hand they were dealt
+    pub hand: InstantWin
+}
+
+/// the strongest of `allowed` that a hand makes, if any
+///
+/// the patterns are for a hand of 13 cards, so larger or smaller
+/// hands never win on the deal. jokers are not counted
+pub fn find_instant_win(hand: &[PlayerCard], allowed: &[InstantWin]) -> Option<InstantWin> {
+    if hand.len() != HAND_SIZE {
+        return None;
+    }
+
+    let mut counts: BTreeMap<Rank, usize> = BTreeMap::new();
+    for card in hand {
+        if let PlayerCard::Card(c) = *card {
+            *counts.entry(c.rank).or_insert(0) += 1;
+        }
+    }
+
+    let pairs = |without: usize, rank: Rank| -> usize {
+        counts.iter().map(|(r, n)| if *r == rank { (n - without) / 2 } else { n / 2 }).sum()
+    };
+
+    let mut found = allowed.iter().cloned().filter(|pattern| match *pattern {
+        InstantWin::Dragon => counts.len() == 13,
+        InstantWin::FourTwos => counts.get(&Rank::Two).cloned().unwrap_or(0) >= 4,
+        InstantWin::SixPairs => pairs(0, Rank::Two) >= 6,
+        InstantWin::FivePairsAndPrial => counts.iter().any(|(r, n)| *n >= 3 && pairs(3, *r) >= 5)
+    }).collect::<Vec<InstantWin>>();
+
+    found.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    found.first().cloned()
+}
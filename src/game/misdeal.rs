@@ -0,0 +1,37 @@
+use cards::card::PlayerCard;
+use cards::types::Rank;
+
+/// a hand so weak its player may ask for the cards to be dealt again
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Misdeal {
+    /// no card ranked above this one - 2s rank highest
+    NoCardAbove(Rank),
+    /// no jacks, queens or kings
+    NoFaceCards
+}
+
+/// whether a hand matches any of `misdeals`
+///
+/// a hand holding a joker is never a misdeal
+pub fn is_misdeal(hand: &[PlayerCard], misdeals: &[Misdeal]) -> bool {
+    if hand.iter().any(|c| !matches!(*c, PlayerCard::Card(_))) {
+        return false;
+    }
+
+    let ranks: Vec<Rank> = hand.iter().map(|c| c.to_card().rank).collect();
+
+    misdeals.iter().any(|misdeal| match *misdeal {
+        Misdeal::NoCardAbove(top) => ranks.iter().all(|r| *r <= top),
+        Misdeal::NoFaceCards => !ranks.iter().any(|r| matches!(*r, Rank::Jack | Rank::Queen | Rank::King))
+    })
+}
+
+/// the seed for the `n`th redeal of a game first shuffled with `seed`
+pub fn redeal_seed(seed: u64, n: usize) -> u64 {
+    if n == 0 {
+        return seed;
+    }
+
+    // spread consecutive redeals across the seed space
+    seed ^ (n as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15)
+}
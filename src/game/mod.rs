@@ -9,3 +9,68 @@ pub mod round;
 
 ///! a game
 pub mod game;
+
+///! errors a move can fail with
+pub mod errors;
+
+///! persistence for games in progress
+pub mod store;
+
+///! every move that can be made from a hand
+pub mod combinations;
+
+///! suggested plays for beginners
+pub mod advisor;
+
+///! splitting a hand into plays
+pub mod hand_analysis;
+
+///! moves that have been played
+pub mod history;
+
+///! cards a player has not yet seen
+pub mod tracker;
+
+///! optional rules
+pub mod rules;
+
+///! what spectators are allowed to see
+pub mod spectator;
+
+///! odds that a move will be beaten
+pub mod probability;
+
+///! consistency checks for game states
+pub mod validator;
+
+///! tamper-evident game states
+#[cfg(feature = "signing")]
+pub mod signing;
+
+///! deals players can check were not rigged
+#[cfg(feature = "signing")]
+pub mod fair_deal;
+
+///! running a game and reporting what happens
+pub mod runner;
+
+///! moves in words
+pub mod describe;
+
+///! whole games as plain text
+pub mod notation;
+
+///! the Vietnamese variant
+pub mod tien_len;
+
+///! hands that win as soon as they are dealt
+pub mod instant_win;
+
+///! hands that can be dealt again
+pub mod misdeal;
+
+///! partnership play
+pub mod teams;
+
+///! cards exchanged between games
+pub mod tribute;
@@ -0,0 +1,445 @@
+use std::fmt;
+
+use cards::card::{self, Card, PlayerCard};
+use cards::deck::Deck;
+use cards::render::{self, Style};
+use game::game::{Game, GameDefinition};
+use game::history::Turn;
+use game::instant_win::InstantWin;
+use game::misdeal::{self, Misdeal};
+use game::rules::{Rules, UndoPolicy, Variant};
+use game::teams::Team;
+use game::tribute::Tribute;
+
+/// a complete game as plain text - header tags, the deal, any
+/// tributes, then one line per move
+///
+/// ```text
+/// [Players "1 2 3 4"]
+/// [Seed "42"]
+/// [Date "2024.06.01"]
+/// [Tribute "1"]
+/// [Result "3 1 4 2"]
+///
+/// 1: 3C 5D 7S ...
+/// 2: 4C 4H JK ...
+///
+/// 2 > 3: 2S < 4D
+///
+/// 1. 1 3C
+/// 2. 2 pass
+/// ```
+///
+/// only rules that differ from the defaults get a tag. the deal is
+/// the hands as dealt, before any tributes - "2 > 3: 2S < 4D" is
+/// player 2 giving the 2S to player 3, who sends back the 4D. a game
+/// dealt again after a misdeal has a "Redeals" tag with the number of
+/// redeals. a move that leaves the player with one card they
+/// announced ends with "last", such as "7. 3 9C 9D last". a result
+/// of "*" is a game still in progress
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameRecord {
+    /// players in seat order
+    pub players: Vec<u64>,
+    /// the seed the deal was shuffled with, if known
+    pub seed: Option<u64>,
+    /// when the game was played
+    pub date: Option<String>,
+    /// optional rules
+    pub rules: Rules,
+    /// times the cards were dealt again after a misdeal
+    pub redeals: usize,
+    /// finishing order, once the game is over
+    pub result: Option<Vec<u64>>,
+    /// each player's hand as dealt, in seat order
+    pub deal: Vec<Vec<PlayerCard>>,
+    /// cards exchanged before play
+    pub tributes: Vec<Tribute>,
+    /// every move, oldest first
+    pub turns: Vec<Turn>
+}
+
+impl GameRecord {
+
+    /// record a game, working out the deal from the hands, history
+    /// and tributes - the seed is kept if the game was dealt from one
+    pub fn new(game: &GameDefinition) -> Result<GameRecord, &'static str> {
+        let players: Vec<u64> = game.players.iter().map(|p| p.get_id()).collect();
+        let tributes: Vec<Tribute> = game.setup.tributes.iter().map(|t| Tribute {
+            from: t.from,
+            to: t.to,
+            given: sorted(&t.given),
+            returned: sorted(&t.returned)
+        }).collect();
+
+        let exchanged = game.players.iter().map(|p| {
+            let played = game.history.iter()
+                .filter(|t| t.player == p.get_id())
+                .flat_map(|t| t.cards.clone());
+
+            p.get_hand().into_iter().chain(played).collect::<Vec<PlayerCard>>()
+        }).collect::<Vec<_>>();
+
+        let mut deal = undo_tributes(&players, exchanged, &tributes)?;
+        for hand in deal.iter_mut() {
+            *hand = sorted(hand);
+        }
+
+        let outcome = Game::load(game.clone())?.get_outcome();
+
+        Ok(GameRecord {
+            players,
+            seed: game.setup.seed,
+            date: None,
+            rules: game.rules.clone(),
+            redeals: game.setup.redeals,
+            result: outcome.map(|o| o.finishing_order),
+            deal,
+            tributes,
+            turns: game.history.iter().map(|t| Turn::new(t.player, t.cards.clone()).with_last_card(t.last_card)).collect()
+        })
+    }
+
+    /// note the seed the deal was shuffled with
+    pub fn with_seed(&self, seed: u64) -> GameRecord {
+        GameRecord { seed: Some(seed), ..self.clone() }
+    }
+
+    /// note when the game was played
+    pub fn with_date(&self, date: &str) -> GameRecord {
+        GameRecord { date: Some(date.to_string()), ..self.clone() }
+    }
+
+    /// read a game, replaying it to check every move is legal
+    pub fn parse(text: &str) -> Result<GameRecord, &'static str> {
+        let mut record = GameRecord {
+            players: vec!(),
+            seed: None,
+            date: None,
+            rules: Rules::default(),
+            redeals: 0,
+            result: None,
+            deal: vec!(),
+            tributes: vec!(),
+            turns: vec!()
+        };
+        let mut hands = vec!();
+
+        for line in text.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+            if line.starts_with('[') {
+                let (name, value) = parse_tag(line)?;
+                match &name[..] {
+                    "Players" => record.players = parse_ids(&value)?,
+                    "Seed" => record.seed = Some(value.parse().map_err(|_| "Invalid seed")?),
+                    "Date" => record.date = Some(value),
+                    "Redeals" => record.redeals = value.parse().map_err(|_| "Invalid redeals")?,
+                    "Result" if value == "*" => record.result = None,
+                    "Result" => record.result = Some(parse_ids(&value)?),
+                    _ => parse_rule(&mut record.rules, &name, &value)?
+                }
+            } else if let Some((number, rest)) = line.split_once(". ") {
+                number.parse::<usize>().map_err(|_| "Invalid move number")?;
+                let (player, cards) = rest.split_once(' ').unwrap_or((rest, ""));
+                let player = player.parse().map_err(|_| "Invalid player")?;
+                let (cards, last_card) = match cards.trim().strip_suffix("last") {
+                    Some(c) => (c.trim(), true),
+                    None => (cards.trim(), false)
+                };
+                let cards = match cards {
+                    "pass" => vec!(),
+                    c => card::parse_cards(c)?
+                };
+                record.turns.push(Turn::new(player, cards).with_last_card(last_card));
+            } else if let Some((players, cards)) = line.split_once(':').filter(|(p, _)| p.contains('>')) {
+                let (from, to) = players.split_once('>').unwrap();
+                let (given, returned) = cards.split_once('<').ok_or("Invalid tribute")?;
+                record.tributes.push(Tribute {
+                    from: from.trim().parse().map_err(|_| "Invalid player")?,
+                    to: to.trim().parse().map_err(|_| "Invalid player")?,
+                    given: card::parse_cards(given)?,
+                    returned: card::parse_cards(returned)?
+                });
+            } else if let Some((player, cards)) = line.split_once(':') {
+                let player: u64 = player.trim().parse().map_err(|_| "Invalid player")?;
+                hands.push((player, card::parse_cards(cards)?));
+            } else {
+                return Err("Unrecognised line");
+            }
+        }
+
+        record.deal = record.players.iter()
+            .map(|id| hands.iter().find(|&&(p, _)| p == *id).map(|(_, h)| h.clone()))
+            .collect::<Option<_>>()
+            .ok_or("Every player needs a hand")?;
+
+        record.replay()?;
+        Ok(record)
+    }
+
+    /// play the game through the engine, returning the final state
+    ///
+    /// the deal is checked against the seed, if there is one, and
+    /// the final placings against the result
+    pub fn replay(&self) -> Result<GameDefinition, &'static str> {
+        if let Some(seed) = self.seed {
+            let deck_size = self.rules.variant.deck_size();
+            let num_decks = self.deal.iter().map(|h| h.len()).sum::<usize>().div_ceil(deck_size);
+            let mut deck = Deck::combine((0..num_decks).map(|_| Deck::new()).collect());
+
+            if self.redeals == 0 {
+                deck.shuffle_seeded(seed);
+            } else {
+                deck.shuffle_seeded(misdeal::redeal_seed(seed, self.redeals));
+            }
+
+            let expected: Vec<Vec<PlayerCard>> = Game::deal(self.players.clone(), deck, self.rules.clone())?
+                .players.iter().map(|p| sorted(&p.get_hand())).collect();
+            let dealt: Vec<Vec<PlayerCard>> = self.deal.iter().map(|h| sorted(h)).collect();
+            if expected != dealt {
+                return Err("The deal does not match the seed");
+            }
+        }
+
+        let hands = do_tributes(&self.players, self.deal.clone(), &self.tributes)?;
+        let mut game = Game::setup_with_hands(self.players.clone(), hands, self.rules.clone())?;
+        game.setup.redeals = self.redeals;
+        game.setup.tributes = self.tributes.clone();
+
+        for turn in &self.turns {
+            // notation ignores the card order, so turn cards to match the game
+            let cards = turn.cards.iter().map(|c| if game.reversed { c.reverse() } else { *c }).collect();
+            game = Game::load(game)?.player_move_announcing(turn.player, cards, turn.last_card)?;
+        }
+
+        let outcome = Game::load(game.clone())?.get_outcome().map(|o| o.finishing_order);
+        if self.result.is_some() && outcome != self.result {
+            return Err("The result does not match the moves");
+        }
+
+        Ok(game)
+    }
+}
+
+impl fmt::Display for GameRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "[Players \"{}\"]", join_ids(&self.players))?;
+        if let Some(seed) = self.seed {
+            writeln!(f, "[Seed \"{}\"]", seed)?;
+        }
+        if let Some(ref date) = self.date {
+            writeln!(f, "[Date \"{}\"]", escape(date))?;
+        }
+        for (name, value) in rule_tags(&self.rules) {
+            writeln!(f, "[{} \"{}\"]", name, value)?;
+        }
+        if self.redeals > 0 {
+            writeln!(f, "[Redeals \"{}\"]", self.redeals)?;
+        }
+        match self.result {
+            Some(ref order) => writeln!(f, "[Result \"{}\"]", join_ids(order))?,
+            None => writeln!(f, "[Result \"*\"]")?
+        }
+
+        writeln!(f)?;
+        for (player, hand) in self.players.iter().zip(self.deal.iter()) {
+            writeln!(f, "{}: {}", player, render::render_cards(hand, Style::Ascii))?;
+        }
+
+        if !self.tributes.is_empty() {
+            writeln!(f)?;
+        }
+        for tribute in &self.tributes {
+            writeln!(f, "{} > {}: {} < {}",
+                     tribute.from,
+                     tribute.to,
+                     render::render_cards(&tribute.given, Style::Ascii),
+                     render::render_cards(&tribute.returned, Style::Ascii))?;
+        }
+
+        if !self.turns.is_empty() {
+            writeln!(f)?;
+        }
+        for (i, turn) in self.turns.iter().enumerate() {
+            let cards = if turn.is_pass() { "pass".to_string() } else { render::render_cards(&turn.cards, Style::Ascii) };
+            let announced = if turn.last_card { " last" } else { "" };
+            writeln!(f, "{}. {} {}{}", i + 1, turn.player, cards, announced)?;
+        }
+
+        Ok(())
+    }
+}
+
+// a played card as it was dealt - the right way up, with a
+// wildcard back to a joker
+fn unplayed(card: PlayerCard) -> PlayerCard {
+    match card {
+        PlayerCard::Card(c) => PlayerCard::Card(Card::new(c.rank, c.suit, false)),
+        PlayerCard::Wildcard(_) | PlayerCard::Joker(_) => PlayerCard::Joker(0)
+    }
+}
+
+// jokers are told apart by id in a deck but not in notation
+fn sorted(cards: &[PlayerCard]) -> Vec<PlayerCard> {
+    let mut c: Vec<PlayerCard> = cards.iter().map(|c| unplayed(*c)).collect();
+    c.sort();
+    c
+}
+
+// tags for the rules that differ from the defaults
+fn rule_tags(rules: &Rules) -> Vec<(&'static str, String)> {
+    let join = |items: Vec<String>| items.join(", ");
+    let mut tags = vec!();
+
+    if rules.variant != Variant::default() {
+        tags.push(("Variant", format!("{:?}", rules.variant)));
+    }
+    if rules.undo != UndoPolicy::default() {
+        tags.push(("Undo", format!("{:?}", rules.undo)));
+    }
+    if !rules.instant_wins.is_empty() {
+        tags.push(("InstantWins", join(rules.instant_wins.iter().map(|w| format!("{:?}", w)).collect())));
+    }
+    if !rules.misdeals.is_empty() {
+        tags.push(("Misdeals", join(rules.misdeals.iter().map(|m| match *m {
+            Misdeal::NoCardAbove(rank) => format!("NoCardAbove {}", rank),
+            Misdeal::NoFaceCards => "NoFaceCards".to_string()
+        }).collect())));
+    }
+    if !rules.teams.is_empty() {
+        tags.push(("Teams", join(rules.teams.iter().map(|t| join_ids(&t.players)).collect())));
+    }
+    if rules.partner_takes_lead {
+        tags.push(("PartnerTakesLead", "Yes".to_string()));
+    }
+    if !rules.tribute.is_empty() {
+        tags.push(("Tribute", rules.tribute.iter().map(|n| n.to_string()).collect::<Vec<String>>().join(" ")));
+    }
+    if rules.forced_highest_single {
+        tags.push(("ForcedHighestSingle", "Yes".to_string()));
+    }
+    if rules.announce_last_card {
+        tags.push(("AnnounceLastCard", "Yes".to_string()));
+    }
+
+    tags
+}
+
+// set a rule from its tag - tags that are not rules are ignored
+fn parse_rule(rules: &mut Rules, name: &str, value: &str) -> Result<(), &'static str> {
+    let list = || value.split(',').map(|item| item.trim()).filter(|item| !item.is_empty());
+
+    match name {
+        "Variant" => rules.variant = match value {
+            "PusoyDos" => Variant::PusoyDos,
+            "TienLen" => Variant::TienLen,
+            _ => return Err("Unknown variant")
+        },
+        "Undo" => rules.undo = match value {
+            "Disabled" => UndoPolicy::Disabled,
+            "BeforeNextAction" => UndoPolicy::BeforeNextAction,
+            "Unrestricted" => UndoPolicy::Unrestricted,
+            _ => return Err("Unknown undo rule")
+        },
+        "InstantWins" => rules.instant_wins = list().map(|item| match item {
+            "Dragon" => Ok(InstantWin::Dragon),
+            "FourTwos" => Ok(InstantWin::FourTwos),
+            "FivePairsAndPrial" => Ok(InstantWin::FivePairsAndPrial),
+            "SixPairs" => Ok(InstantWin::SixPairs),
+            _ => Err("Unknown instant win")
+        }).collect::<Result<_, _>>()?,
+        "Misdeals" => rules.misdeals = list().map(|item| match item.split_once(' ') {
+            Some(("NoCardAbove", rank)) => Ok(Misdeal::NoCardAbove(card::parse_rank(rank)?)),
+            None if item == "NoFaceCards" => Ok(Misdeal::NoFaceCards),
+            _ => Err("Unknown misdeal")
+        }).collect::<Result<_, _>>()?,
+        "Teams" => rules.teams = list().map(|item| parse_ids(item).map(|players| Team{ players })).collect::<Result<_, _>>()?,
+        "PartnerTakesLead" => rules.partner_takes_lead = parse_yes(value)?,
+        "Tribute" => rules.tribute = value.split_whitespace()
+            .map(|n| n.parse().map_err(|_| "Invalid tribute"))
+            .collect::<Result<_, _>>()?,
+        "ForcedHighestSingle" => rules.forced_highest_single = parse_yes(value)?,
+        "AnnounceLastCard" => rules.announce_last_card = parse_yes(value)?,
+        _ => ()
+    }
+
+    Ok(())
+}
+
+fn parse_yes(value: &str) -> Result<bool, &'static str> {
+    match value {
+        "Yes" => Ok(true),
+        "No" => Ok(false),
+        _ => Err("Expected Yes or No")
+    }
+}
+
+// the hands once every tribute has been given and returned
+fn do_tributes(players: &[u64], hands: Vec<Vec<PlayerCard>>, tributes: &[Tribute]) -> Result<Vec<Vec<PlayerCard>>, &'static str> {
+    let mut hands = hands;
+    for tribute in tributes {
+        hands = move_cards(players, hands, tribute.from, tribute.to, &tribute.given)?;
+        hands = move_cards(players, hands, tribute.to, tribute.from, &tribute.returned)?;
+    }
+    Ok(hands)
+}
+
+// the hands as they were dealt, before any tributes
+fn undo_tributes(players: &[u64], hands: Vec<Vec<PlayerCard>>, tributes: &[Tribute]) -> Result<Vec<Vec<PlayerCard>>, &'static str> {
+    let mut hands: Vec<Vec<PlayerCard>> = hands.iter().map(|h| sorted(h)).collect();
+    for tribute in tributes.iter().rev() {
+        hands = move_cards(players, hands, tribute.from, tribute.to, &tribute.returned)?;
+        hands = move_cards(players, hands, tribute.to, tribute.from, &tribute.given)?;
+    }
+    Ok(hands)
+}
+
+fn move_cards(players: &[u64],
+              mut hands: Vec<Vec<PlayerCard>>,
+              from: u64,
+              to: u64,
+              cards: &[PlayerCard]) -> Result<Vec<Vec<PlayerCard>>, &'static str> {
+    let from = players.iter().position(|id| *id == from).ok_or("Invalid player")?;
+    let to = players.iter().position(|id| *id == to).ok_or("Invalid player")?;
+
+    for card in cards {
+        let pos = hands[from].iter().position(|c| c == card).ok_or("A tribute was not in the hand")?;
+        let card = hands[from].remove(pos);
+        hands[to].push(card);
+    }
+
+    Ok(hands)
+}
+
+fn parse_tag(line: &str) -> Result<(String, String), &'static str> {
+    let inner = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')).ok_or("Invalid tag")?;
+    let (name, value) = inner.split_once(' ').ok_or("Invalid tag")?;
+    let value = value.trim().strip_prefix('"').and_then(|v| v.strip_suffix('"')).ok_or("Invalid tag")?;
+
+    Ok((name.to_string(), unescape(value)))
+}
+
+fn parse_ids(text: &str) -> Result<Vec<u64>, &'static str> {
+    text.split_whitespace().map(|id| id.parse().map_err(|_| "Invalid player")).collect()
+}
+
+fn join_ids(ids: &[u64]) -> String {
+    ids.iter().map(|id| id.to_string()).collect::<Vec<String>>().join(" ")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn unescape(text: &str) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => result.extend(chars.next()),
+            c => result.push(c)
+        }
+    }
+
+    result
+}
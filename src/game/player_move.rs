@@ -1,5 +1,6 @@
 use cards::card::{ Card, PlayerCard };
 use cards::types::*;
+use game::tien_len::{self, TienLenPlay};
 use std::collections::HashMap;
 
 use std::cmp::Ordering;
@@ -24,7 +25,10 @@ pub enum Move{
     /// 3 of a kind
     Prial(Card, Card, Card),
     /// 5 card trick
-    FiveCardTrick(Trick)
+    FiveCardTrick(Trick),
+    /// a Tiến lên play of four of a kind, a sequence or
+    /// consecutive pairs
+    TienLen(TienLenPlay)
 }
 
 impl Move {
@@ -34,10 +38,24 @@ impl Move {
           Move::Single(c) => Move::Single(c.reverse()),
           Move::Pair(a, b) => Move::Pair(a.reverse(), b.reverse()),
           Move::Prial(a, b, c) => Move::Prial(a.reverse(), b.reverse(), c.reverse()),
-          Move::FiveCardTrick(t) => Move::FiveCardTrick(t.reverse())
+          Move::FiveCardTrick(t) => Move::FiveCardTrick(t.reverse()),
+          // Tiến lên is never reversed
+          Move::TienLen(p) => Move::TienLen(p)
        }
     }
 
+    /// the cards in the move - a pass has none
+    pub fn get_cards(&self) -> Vec<Card> {
+        match *self {
+            Move::Pass => vec!(),
+            Move::Single(a) => vec!(a),
+            Move::Pair(a, b) => vec!(a, b),
+            Move::Prial(a, b, c) => vec!(a, b, c),
+            Move::FiveCardTrick(t) => t.cards.to_vec(),
+            Move::TienLen(p) => p.get_cards()
+        }
+    }
+
     fn is_pass(&self) -> bool {
         Move::Pass == *self
     }
@@ -77,23 +95,30 @@ impl Move {
         }
     }
 
-    fn get_top_card(&self) -> Card {
+    /// the highest card in the move - a pass has no cards
+    pub fn get_top_card(&self) -> Card {
         match *self {
             Move::Pass => panic!("pass has no top card!"),
             Move::Single(a) => get_max_card(vec!(a)),
             Move::Pair(a, b) => get_max_card(vec!(a, b)),
             Move::Prial(a, b, c) => get_max_card(vec!(a, b, c)),
-            Move::FiveCardTrick(t) => get_max_card(t.cards.to_vec())
+            Move::FiveCardTrick(t) => get_max_card(t.cards.to_vec()),
+            Move::TienLen(p) => p.get_top_card()
         }
     }
 
-    fn types_match(&self, other: &Move) -> bool {
+    /// whether two moves are of the same kind and can be compared
+    pub fn types_match(&self, other: &Move) -> bool {
         match *self {
             Move::Pass => other.is_pass(),
             Move::Single(_) => other.is_single(),
             Move::Pair(_,_) => other.is_pair(),
             Move::Prial(_, _, _) => other.is_prial(),
-            Move::FiveCardTrick(_) => other.is_five_card_trick()
+            Move::FiveCardTrick(_) => other.is_five_card_trick(),
+            Move::TienLen(p) => match *other {
+                Move::TienLen(q) => p.get_combination() == q.get_combination(),
+                _ => false
+            }
         }
     }
 
@@ -109,6 +134,7 @@ impl PartialOrd for Move {
         match *self {
             Move::Pass =>  Some(Ordering::Equal),
             Move::FiveCardTrick(t) => t.partial_cmp(&other.get_five_card_trick().unwrap()),
+            Move::TienLen(p) => Some(tien_len::compare(&p.get_top_card(), &other.get_top_card())),
             _ => self.get_top_card().partial_cmp(&other.get_top_card())
         }
 
@@ -0,0 +1,96 @@
+use rand::{Rng, SeedableRng, StdRng};
+
+use cards::card::PlayerCard;
+use game::combinations;
+use game::game::GameDefinition;
+use game::player_move::Move;
+use game::tracker::CardTracker;
+
+/// the chance that an opponent holds cards that beat a move
+#[derive(Clone, Debug, PartialEq)]
+pub struct BeatEstimate {
+    /// the opponent
+    pub player: u64,
+    /// between 0 and 1
+    pub probability: f64,
+    /// whether every possible hand was checked, rather than a sample
+    pub exact: bool
+}
+
+/// settings for estimating beat probabilities
+#[derive(Clone, Debug, PartialEq)]
+pub struct Estimator {
+    /// the most possible hands checked one by one before sampling
+    pub exact_limit: u64,
+    /// hands drawn when sampling - at least one is always drawn
+    pub samples: usize,
+    /// seed for sampling, so estimates can be reproduced
+    pub seed: usize
+}
+
+impl Default for Estimator {
+    fn default() -> Estimator {
+        Estimator {
+            exact_limit: 20000,
+            samples: 2000,
+            seed: 0
+        }
+    }
+}
+
+impl Estimator {
+
+    /// estimate, for each `(player id, hand size)`, the chance that a
+    /// hand of that size dealt from `unseen` can beat `proposed`
+    ///
+    /// `unseen` may include jokers, which are used as wildcards
+    pub fn estimate(&self, unseen: &[PlayerCard], opponents: &[(u64, usize)], proposed: Move) -> Vec<BeatEstimate> {
+        let mut rng: StdRng = SeedableRng::from_seed(&[self.seed][..]);
+
+        opponents.iter().map(|&(player, hand_size)| {
+            let size = hand_size.min(unseen.len());
+            let exact = binomial(unseen.len() as u64, size as u64) <= self.exact_limit;
+
+            let probability = if exact {
+                let hands = combinations::choose(unseen, size);
+                let beats = hands.iter().filter(|h| combinations::can_beat(h, proposed)).count();
+                beats as f64 / hands.len() as f64
+            } else {
+                let samples = self.samples.max(1);
+                let mut pool = unseen.to_vec();
+                let beats = (0..samples).filter(|_| {
+                    rng.shuffle(&mut pool);
+                    combinations::can_beat(&pool[..size], proposed)
+                }).count();
+                beats as f64 / samples as f64
+            };
+
+            BeatEstimate { player, probability, exact }
+        }).collect()
+    }
+
+    /// estimate from `player_id`'s point of view the chance that each
+    /// opponent still holding cards can beat `proposed`
+    pub fn estimate_for_player(&self, game: &GameDefinition, player_id: u64, proposed: Move) -> Result<Vec<BeatEstimate>, &'static str> {
+        let tracker = CardTracker::new(game, player_id)?;
+
+        let mut unseen = tracker.get_unseen();
+        unseen.extend((0..tracker.unseen_jokers()).map(|n| PlayerCard::Joker(n as u64)));
+
+        let opponents: Vec<(u64, usize)> = game.players.iter()
+            .filter(|p| p.get_id() != player_id && p.remaining_cards() > 0)
+            .map(|p| (p.get_id(), p.remaining_cards()))
+            .collect();
+
+        Ok(self.estimate(&unseen, &opponents, proposed))
+    }
+}
+
+// n choose k, saturating rather than overflowing
+fn binomial(n: u64, k: u64) -> u64 {
+    let k = k.min(n - k);
+
+    (0..k).fold(1u64, |acc, i| {
+        acc.saturating_mul(n - i) / (i + 1)
+    })
+}
@@ -13,7 +13,7 @@ pub struct RoundDefinition{
 }
 
 /// single round
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Round{
     players: Vec<u64>,
     current_player: u64,
@@ -59,6 +59,48 @@ impl Round {
         self.process_move(player_id, Move::Pass)
     }
 
+    /// play a move that has already been checked against the table,
+    /// only keeping track of turns and passes
+    pub fn play_unchecked(&self, player_id: u64, new_move: Move) -> Result<Round, Round> {
+        if player_id != self.current_player {
+            return Err(self.clone());
+        }
+
+        if self.last_move == Move::Pass && new_move == Move::Pass {
+            return Err(self.clone());
+        }
+
+        Ok(self.advance(self.determine_next_player(), new_move))
+    }
+
+    fn advance(&self, next_player: u64, new_move: Move) -> Round {
+        let pass_count = if new_move == Move::Pass {
+            self.pass_count + 1
+        } else {
+            0
+        };
+
+        // this essentially passes the exiting players move to the next player
+        // giving them the benefit of starting if everyone passes
+        let last_move = if self.pass_count == -1 && new_move == Move::Pass {
+            self.last_move
+        } else if pass_count  >= self.players.len() as i64 - 1 {
+            Move::Pass
+        } else if new_move == Move::Pass {
+            self.last_move
+        } else {
+            new_move
+        };
+
+        Round{
+            players: self.players.clone(),
+            current_player: next_player,
+            last_move: last_move,
+            pass_count: pass_count,
+            first_round: false
+        }
+    }
+
     fn process_move(&self, player_id: u64, new_move: Move) -> Result<Round, Round> {
 
         if player_id != self.current_player {
@@ -71,33 +113,7 @@ impl Round {
 
         let next_player = self.determine_next_player();
         if self.last_move == Move::Pass || new_move == Move::Pass {
-
-            let pass_count = if new_move == Move::Pass {
-                self.pass_count + 1
-            } else {
-                0
-            };
-
-            // this essentially passes the exiting players move to the next player
-            // giving them the benefit of starting if everyone passes
-            let last_move = if self.pass_count == -1 && new_move == Move::Pass {
-                self.last_move
-            } else if pass_count  >= self.players.len() as i64 - 1 {
-                Move::Pass
-            } else if new_move == Move::Pass {
-                self.last_move
-            } else {
-                new_move 
-            };
-           
-            Ok(Round{
-                players: self.players.clone(), 
-                current_player: next_player,
-                last_move: last_move,
-                pass_count: pass_count,
-                first_round: false
-            })
- 
+            Ok(self.advance(next_player, new_move))
         } else if self.valid_move(new_move) {
 
             // if unbeatable -- set last move to pass and current_player to current_player
@@ -172,6 +188,10 @@ impl Round {
         Round::new(self.players.clone(), self.current_player, self.last_move, c, self.first_round)
     }
 
+    pub fn set_current_player(&self, player_id: u64) -> Round {
+        Round::new(self.players.clone(), player_id, self.last_move, self.pass_count, self.first_round)
+    }
+
     fn determine_next_player(&self) -> u64 {
         if self.current_player == *self.players.last().unwrap() {
             *self.players.first().unwrap()
@@ -225,15 +245,7 @@ impl Round {
     }
 
     fn includes_three(&self, new_move: Move) -> bool {
-        let cards = match new_move {
-            Move::Single(x) => vec!(x),
-            Move::Pair(x, y) => vec!(x, y),
-            Move::Prial(x, y, z) => vec!(x, y, z),
-            Move::FiveCardTrick(t) => t.cards.to_vec(),
-            Move::Pass => vec!()
-        };
-
-        for card in cards.iter() {
+        for card in new_move.get_cards().iter() {
             if *card == card!(Three, Clubs).to_card(){
                 return true;
             }
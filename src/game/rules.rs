@@ -0,0 +1,120 @@
+use cards::card::PlayerCard;
+use cards::deck::Deck;
+use game::instant_win::InstantWin;
+use game::player_move::{self, Move};
+use game::tien_len::TienLenMove;
+use game::misdeal::Misdeal;
+use game::teams::Team;
+
+/// optional rules chosen when a game is set up
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Rules {
+    /// when moves may be taken back
+    pub undo: UndoPolicy,
+    /// which game is being played
+    pub variant: Variant,
+    /// dealt hands that win straight away - none by default
+    pub instant_wins: Vec<InstantWin>,
+    /// dealt hands that may be dealt again - none by default
+    pub misdeals: Vec<Misdeal>,
+    /// partnerships - everyone plays for themselves by default
+    pub teams: Vec<Team>,
+    /// a player who goes out hands the lead to their partner
+    /// if nobody beats their last play
+    pub partner_takes_lead: bool,
+    /// cards the last player gives the winner before the next game,
+    /// then the second last the runner up and so on - none by default
+    pub tribute: Vec<usize>,
+    /// a player must play their highest single card when playing
+    /// a single and the next player holds one card
+    pub forced_highest_single: bool,
+    /// a player must announce when they play down to one card
+    pub announce_last_card: bool
+}
+
+/// games played with the same engine
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum Variant {
+    /// the standard game
+    #[default]
+    PusoyDos,
+    /// the Vietnamese game, with sequences of any length, runs of
+    /// pairs that chop 2s, no jokers and suits ordered ♠ < ♣ < ♦ < ♥
+    TienLen
+}
+
+impl Variant {
+
+    /// the cards played with from `num_decks` decks
+    pub fn get_deck(&self, num_decks: usize) -> Deck {
+        let deck = Deck::combine((0..num_decks).map(|_| Deck::new()).collect());
+        self.trim_deck(&deck)
+    }
+
+    /// cards in one deck, including any jokers
+    pub fn deck_size(&self) -> usize {
+        self.get_deck(1).count()
+    }
+
+    /// jokers in one deck
+    pub fn jokers_per_deck(&self) -> usize {
+        self.get_deck(1).get_cards().iter().filter(|c| !self.is_real(c)).count()
+    }
+
+    /// whether a card is played with at all - Tiến lên has no jokers
+    pub fn uses_card(&self, card: &PlayerCard) -> bool {
+        match *self {
+            Variant::PusoyDos => true,
+            Variant::TienLen => self.is_real(card)
+        }
+    }
+
+    /// a shuffled deck without the cards that are not played with
+    pub fn trim_deck(&self, deck: &Deck) -> Deck {
+        match *self {
+            Variant::PusoyDos => deck.clone(),
+            Variant::TienLen => deck.without_jokers()
+        }
+    }
+
+    /// deal out a shuffled deck, leaving out cards that are
+    /// not played with
+    pub fn deal(&self, deck: &Deck, players: usize) -> Vec<Vec<PlayerCard>> {
+        self.trim_deck(deck).deal(players)
+    }
+
+    /// the move some cards make, if they make one - no cards
+    /// is a pass
+    ///
+    /// a joker must be played as a wildcard, so a bare joker makes
+    /// no move
+    pub fn build_move(&self, cards: &[PlayerCard]) -> Option<Move> {
+        if cards.iter().any(|c| matches!(*c, PlayerCard::Joker(_))) {
+            return None;
+        }
+
+        match *self {
+            Variant::PusoyDos => player_move::build_move(cards.to_vec()),
+            Variant::TienLen if cards.is_empty() => Some(Move::Pass),
+            Variant::TienLen => TienLenMove::build(cards).map(|m| m.to_move())
+        }
+    }
+
+    fn is_real(&self, card: &PlayerCard) -> bool {
+        matches!(*card, PlayerCard::Card(_))
+    }
+}
+
+/// when moves may be taken back
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum UndoPolicy {
+    /// moves are final
+    #[default]
+    Disabled,
+    /// a player may take back their own last move until
+    /// the next player has acted
+    BeforeNextAction,
+    /// any number of moves may be taken back - for practice games
+    Unrestricted
+}
@@ -0,0 +1,157 @@
+use cards::card::PlayerCard;
+use game::game::{Game, GameDefinition, Outcome};
+use game::player_move::Move;
+
+/// something that happened during a game
+#[derive(Clone, Debug, PartialEq)]
+pub enum GameEvent {
+    /// it is now this player's turn
+    TurnStarted(u64),
+    /// a player played cards
+    MovePlayed {
+        /// who played
+        player: u64,
+        /// the cards as played
+        cards: Vec<PlayerCard>,
+        /// the move they made
+        player_move: Move
+    },
+    /// a player passed
+    Passed(u64),
+    /// everyone else passed, so this player leads the next round
+    RoundCleared(u64),
+    /// four or five of a kind reversed the card order - true
+    /// if the order is now reversed
+    CardsReversed(bool),
+    /// a player ran out of cards, finishing in `place` (1 is first)
+    PlayerFinished {
+        /// who finished
+        player: u64,
+        /// their finishing place
+        place: usize
+    },
+    /// fewer than two players have cards left
+    GameOver(Outcome)
+}
+
+/// receives events from a `GameRunner`
+pub trait GameObserver {
+    /// called for each event, in the order they happened
+    fn on_event(&mut self, event: &GameEvent);
+}
+
+impl<F> GameObserver for F where F: FnMut(&GameEvent) {
+    fn on_event(&mut self, event: &GameEvent) {
+        self(event)
+    }
+}
+
+/// plays moves on a game and tells observers what happened
+pub struct GameRunner {
+    game: GameDefinition,
+    observers: Vec<Box<dyn GameObserver>>
+}
+
+impl GameRunner {
+
+    /// run an existing game
+    pub fn new(game: GameDefinition) -> GameRunner {
+        GameRunner {
+            game,
+            observers: vec!()
+        }
+    }
+
+    /// add an observer, which will hear about every later event
+    pub fn subscribe(&mut self, observer: Box<dyn GameObserver>) {
+        self.observers.push(observer);
+    }
+
+    /// announce whose turn it is, such as when the game begins
+    pub fn start(&mut self) -> Result<(), &'static str> {
+        let game = Game::load(self.game.clone())?;
+
+        if let Some(player) = game.get_next_player() {
+            self.emit(&[GameEvent::TurnStarted(player.get_id())]);
+        }
+
+        Ok(())
+    }
+
+    /// play a move, telling observers what happened if it succeeds
+    pub fn player_move(&mut self, player_id: u64, cards: Vec<PlayerCard>) -> Result<GameDefinition, &'static str> {
+        self.player_move_announcing(player_id, cards, false)
+    }
+
+    /// play a move, saying whether the player has announced it
+    /// leaves them with their last card
+    pub fn player_move_announcing(&mut self, player_id: u64, cards: Vec<PlayerCard>, last_card: bool) -> Result<GameDefinition, &'static str> {
+        let before = self.game.clone();
+        let after = Game::load(before.clone())?.player_move_announcing(player_id, cards, last_card)?;
+
+        let events = get_events(&before, &after)?;
+        self.game = after.clone();
+        self.emit(&events);
+
+        Ok(after)
+    }
+
+    /// the game as it stands
+    pub fn get_game(&self) -> GameDefinition {
+        self.game.clone()
+    }
+
+    fn emit(&mut self, events: &[GameEvent]) {
+        for event in events {
+            for observer in self.observers.iter_mut() {
+                observer.on_event(event);
+            }
+        }
+    }
+}
+
+// work out what a move changed, from the turn the game recorded
+fn get_events(before: &GameDefinition, after: &GameDefinition) -> Result<Vec<GameEvent>, &'static str> {
+    let turn = after.history.last().ok_or("The move was not recorded")?;
+    let mut events = vec!();
+
+    match after.rules.variant.build_move(&turn.cards) {
+        Some(Move::Pass) | None => events.push(GameEvent::Passed(turn.player)),
+        Some(player_move) => events.push(GameEvent::MovePlayed {
+            player: turn.player,
+            cards: turn.cards.clone(),
+            player_move
+        })
+    }
+
+    if after.reversed != before.reversed {
+        events.push(GameEvent::CardsReversed(after.reversed));
+    }
+
+    for (i, player) in after.winners.iter().enumerate().skip(before.winners.len()) {
+        events.push(GameEvent::PlayerFinished { player: *player, place: i + 1 });
+    }
+
+    let game = Game::load(after.clone())?;
+
+    if let Some(outcome) = game.get_outcome() {
+        events.push(GameEvent::GameOver(outcome));
+        return Ok(events);
+    }
+
+    let next = game.get_next_player().map(|p| p.get_id());
+
+    // the table is only left empty once everyone has passed, or
+    // after a move nobody can beat
+    if after.round.get_last_move() == Move::Pass {
+        if let Some(leader) = next {
+            events.push(GameEvent::RoundCleared(leader));
+        }
+    }
+
+    if let Some(player) = next {
+        events.push(GameEvent::TurnStarted(player));
+    }
+
+    Ok(events)
+}
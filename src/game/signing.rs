@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use game::game::{Game, GameDefinition};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// a game state with a signature over its contents
+///
+/// the state is kept as the JSON that was signed rather than as a
+/// `GameDefinition`, so verifying never depends on serializing it
+/// the same way again
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SignedGame {
+    /// the key the state was signed with
+    pub key_id: String,
+    /// the state as signed, serialized as JSON
+    pub state: String,
+    /// hex encoded HMAC-SHA256 of the key id and the state
+    pub signature: String
+}
+
+/// secret keys for signing game states
+///
+/// new states are signed with the current key, while any key still
+/// in the keyring is accepted when verifying, so keys can be rotated
+/// without invalidating games already handed out
+#[derive(Clone)]
+pub struct Keyring {
+    keys: BTreeMap<String, Vec<u8>>,
+    current: String
+}
+
+impl Keyring {
+
+    /// create a keyring that signs with `secret`
+    pub fn new(key_id: &str, secret: &[u8]) -> Keyring {
+        let mut keys = BTreeMap::new();
+        keys.insert(key_id.to_string(), secret.to_vec());
+
+        Keyring {
+            keys,
+            current: key_id.to_string()
+        }
+    }
+
+    /// sign with a new key from now on, still accepting older keys
+    pub fn rotate(&self, key_id: &str, secret: &[u8]) -> Keyring {
+        let mut keys = self.keys.clone();
+        keys.insert(key_id.to_string(), secret.to_vec());
+
+        Keyring {
+            keys,
+            current: key_id.to_string()
+        }
+    }
+
+    /// stop accepting states signed with an old key
+    pub fn retire(&self, key_id: &str) -> Result<Keyring, &'static str> {
+        if key_id == self.current {
+            return Err("Cannot retire the current key");
+        }
+
+        let mut keys = self.keys.clone();
+        keys.remove(key_id);
+
+        Ok(Keyring {
+            keys,
+            current: self.current.clone()
+        })
+    }
+
+    /// the key new states are signed with
+    pub fn get_current_key(&self) -> String {
+        self.current.clone()
+    }
+
+    /// sign a game state with the current key
+    pub fn sign(&self, game: &GameDefinition) -> Result<SignedGame, &'static str> {
+        let state = ::serde_json::to_string(game).map_err(|_| "Could not serialize game")?;
+        let mac = self.get_mac(&self.current, &state)?;
+
+        Ok(SignedGame {
+            key_id: self.current.clone(),
+            state,
+            signature: to_hex(&mac.finalize().into_bytes())
+        })
+    }
+
+    /// the state, if its signature is valid for a key in the keyring
+    pub fn verify(&self, signed: &SignedGame) -> Result<GameDefinition, &'static str> {
+        let mac = self.get_mac(&signed.key_id, &signed.state)?;
+        let expected = from_hex(&signed.signature).ok_or("Signature does not match")?;
+
+        mac.verify_slice(&expected).map_err(|_| "Signature does not match")?;
+        ::serde_json::from_str(&signed.state).map_err(|_| "Could not read game")
+    }
+
+    /// load a game, refusing a state whose signature does not verify
+    pub fn load(&self, signed: &SignedGame) -> Result<Game, &'static str> {
+        Game::load(self.verify(signed)?)
+    }
+
+    /// whether a state was signed with an older key and should be
+    /// signed again
+    pub fn needs_resign(&self, signed: &SignedGame) -> bool {
+        signed.key_id != self.current
+    }
+
+    fn get_mac(&self, key_id: &str, state: &str) -> Result<HmacSha256, &'static str> {
+        let secret = self.keys.get(key_id).ok_or("Unknown signing key")?;
+
+        let mut mac = HmacSha256::new_from_slice(secret).map_err(|_| "Invalid signing key")?;
+        mac.update(&canonical(key_id, state));
+
+        Ok(mac)
+    }
+}
+
+// the bytes that are signed - the key id is included so a signature
+// cannot be moved to a different key
+fn canonical(key_id: &str, state: &str) -> Vec<u8> {
+    let mut bytes = key_id.as_bytes().to_vec();
+    bytes.push(0);
+    bytes.extend(state.as_bytes());
+
+    bytes
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return None;
+    }
+
+    (0..text.len()).step_by(2)
+        .map(|i| u8::from_str_radix(&text[i..i + 2], 16).ok())
+        .collect()
+}
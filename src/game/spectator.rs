@@ -0,0 +1,107 @@
+use cards::card::PlayerCard;
+use game::game::{Game, GameDefinition};
+use game::history::Turn;
+use game::player_move::Move;
+
+/// how much a spectator is allowed to see
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RevealPolicy {
+    /// the table, card counts and history only
+    PublicOnly,
+    /// everything, including hands, as it was this many moves ago -
+    /// at least one
+    Delayed(usize),
+    /// hands are only shown once the game is finished
+    PostGame
+}
+
+/// a seat as seen by a spectator
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SeatView {
+    /// player id
+    pub id: u64,
+    /// number of cards held
+    pub remaining_cards: usize,
+    /// the hand, if the policy allows it to be seen
+    pub hand: Option<Vec<PlayerCard>>
+}
+
+/// a game as seen by a spectator
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SpectatorView {
+    /// the policy the view was made with
+    pub policy: RevealPolicy,
+    /// every seat at the table
+    pub seats: Vec<SeatView>,
+    /// the move on the table
+    pub last_move: Move,
+    /// who plays next
+    pub next_player: Option<u64>,
+    /// order of winners
+    pub winners: Vec<u64>,
+    /// whether cards are reversed
+    pub reversed: bool,
+    /// moves made so far, without any hidden information
+    pub history: Vec<Turn>,
+    /// whether the game is finished
+    pub finished: bool
+}
+
+impl SpectatorView {
+
+    /// build a view of a game
+    ///
+    /// a delayed view shows the whole table as it was, so that
+    /// cards played since cannot be used to work out live hands
+    pub fn new(game: &Game, policy: RevealPolicy) -> Result<SpectatorView, &'static str> {
+        let live = game.export();
+
+        let (game_def, reveal) = match policy {
+            RevealPolicy::PublicOnly => (live, false),
+            RevealPolicy::PostGame => (live, game.is_finished()),
+            // no delay would show the live hands
+            RevealPolicy::Delayed(0) => return Err("A delayed view must be at least one move behind"),
+            RevealPolicy::Delayed(n) => {
+                if game.is_finished() {
+                    (live, true)
+                } else if live.history.len() < n {
+                    (game.rewind(live.history.len())?, false)
+                } else {
+                    (game.rewind(n)?, true)
+                }
+            }
+        };
+
+        Ok(SpectatorView::from_definition(game_def, policy, reveal))
+    }
+
+    /// serialize the view for broadcast
+    pub fn to_json(&self) -> Result<String, &'static str> {
+        ::serde_json::to_string(self).map_err(|_| "Could not serialize view")
+    }
+
+    fn from_definition(game_def: GameDefinition, policy: RevealPolicy, reveal: bool) -> SpectatorView {
+        let game = Game::load(game_def.clone()).unwrap();
+
+        let seats = game_def.players.iter().map(|p| SeatView {
+            id: p.get_id(),
+            remaining_cards: p.remaining_cards(),
+            hand: if reveal { Some(p.get_hand()) } else { None }
+        }).collect();
+
+        let history = game_def.history.iter()
+            .map(|turn| Turn::new(turn.player, turn.cards.clone()))
+            .collect();
+
+        SpectatorView {
+            policy,
+            seats,
+            last_move: game.get_last_move(),
+            next_player: if game.is_finished() { None } else { game.get_next_player().map(|p| p.get_id()) },
+            winners: game_def.winners,
+            reversed: game_def.reversed,
+            history,
+            finished: game.is_finished()
+        }
+    }
+}
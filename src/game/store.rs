@@ -0,0 +1,274 @@
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde_json;
+
+use cards::card::PlayerCard;
+use game::game::{Game, GameDefinition};
+
+/// a game as held by a `GameStore`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StoredGame {
+    /// id assigned by the store
+    pub id: u64,
+    /// incremented on every successful save
+    pub version: u64,
+    /// the game itself
+    pub game: GameDefinition
+}
+
+/// persistence for games in progress
+pub trait GameStore {
+    /// store a new game, assigning it an id
+    fn create(&mut self, game: GameDefinition) -> Result<StoredGame, &'static str>;
+
+    /// fetch a stored game
+    fn load(&self, id: u64) -> Result<StoredGame, &'static str>;
+
+    /// replace a stored game, failing if it has been saved
+    /// since `expected_version` was loaded
+    fn save(&mut self, id: u64, game: GameDefinition, expected_version: u64) -> Result<StoredGame, &'static str>;
+
+    /// ids of all games that are not yet finished
+    fn list_active(&self) -> Result<Vec<u64>, &'static str>;
+}
+
+/// load a game, apply a move and save it in one step
+///
+/// `expected_version` is the version the player saw when choosing
+/// their move, so a move made against a stale game is rejected
+pub fn play_move<S: GameStore>(store: &mut S,
+                               id: u64,
+                               expected_version: u64,
+                               player_id: u64,
+                               cards: Vec<PlayerCard>) -> Result<StoredGame, &'static str> {
+    play_move_announcing(store, id, expected_version, player_id, cards, false)
+}
+
+/// as `play_move`, saying whether the player has announced the
+/// move leaves them with their last card
+pub fn play_move_announcing<S: GameStore>(store: &mut S,
+                                          id: u64,
+                                          expected_version: u64,
+                                          player_id: u64,
+                                          cards: Vec<PlayerCard>,
+                                          last_card: bool) -> Result<StoredGame, &'static str> {
+    let stored = store.load(id)?;
+
+    if stored.version != expected_version {
+        return Err("Game has changed since it was loaded");
+    }
+
+    let game = Game::load(stored.game)?;
+    let next = game.player_move_announcing(player_id, cards, last_card)?;
+
+    store.save(id, next, stored.version)
+}
+
+fn is_active(game: &GameDefinition) -> bool {
+    match Game::load(game.clone()) {
+        Ok(g) => !g.is_finished(),
+        Err(_) => false
+    }
+}
+
+/// keeps games in memory
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStore {
+    games: BTreeMap<u64, StoredGame>,
+    next_id: u64
+}
+
+impl MemoryStore {
+    /// create an empty store
+    pub fn new() -> MemoryStore {
+        MemoryStore::default()
+    }
+}
+
+impl GameStore for MemoryStore {
+    fn create(&mut self, game: GameDefinition) -> Result<StoredGame, &'static str> {
+        let stored = StoredGame {
+            id: self.next_id,
+            version: 0,
+            game
+        };
+
+        self.next_id += 1;
+        self.games.insert(stored.id, stored.clone());
+
+        Ok(stored)
+    }
+
+    fn load(&self, id: u64) -> Result<StoredGame, &'static str> {
+        self.games.get(&id).cloned().ok_or("Game not found")
+    }
+
+    fn save(&mut self, id: u64, game: GameDefinition, expected_version: u64) -> Result<StoredGame, &'static str> {
+        let stored = match self.games.get_mut(&id) {
+            Some(s) => s,
+            None => return Err("Game not found")
+        };
+
+        if stored.version != expected_version {
+            return Err("Game has changed since it was loaded");
+        }
+
+        stored.version += 1;
+        stored.game = game;
+
+        Ok(stored.clone())
+    }
+
+    fn list_active(&self) -> Result<Vec<u64>, &'static str> {
+        Ok(self.games.values()
+            .filter(|s| is_active(&s.game))
+            .map(|s| s.id)
+            .collect())
+    }
+}
+
+/// keeps each game as a JSON document named `<id>.json` in a directory
+///
+/// a writer holds `<id>.json.lock` while it checks the version and
+/// writes, so processes sharing a directory cannot overwrite each
+/// other - a writer that finds the lock taken is refused
+#[derive(Clone, Debug)]
+pub struct FileStore {
+    dir: PathBuf
+}
+
+// tells apart the temporary files of writers in one process
+static WRITES: AtomicUsize = AtomicUsize::new(0);
+
+// removes its lock file when dropped
+struct FileLock(PathBuf);
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+impl FileStore {
+    /// use `dir` for storage, creating it if needed
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Result<FileStore, &'static str> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|_| "Could not create game directory")?;
+
+        Ok(FileStore { dir })
+    }
+
+    fn path(&self, id: u64) -> PathBuf {
+        self.dir.join(format!("{}.json", id))
+    }
+
+    fn ids(&self) -> Result<Vec<u64>, &'static str> {
+        let entries = fs::read_dir(&self.dir).map_err(|_| "Could not read game directory")?;
+        let mut ids = vec!();
+
+        for entry in entries {
+            let path = entry.map_err(|_| "Could not read game directory")?.path();
+
+            if path.extension().is_some_and(|e| e == "json") {
+                if let Some(id) = path.file_stem().and_then(|s| s.to_str()).and_then(|s| s.parse().ok()) {
+                    ids.push(id);
+                }
+            }
+        }
+
+        ids.sort();
+        Ok(ids)
+    }
+
+    fn lock(&self, id: u64) -> Result<FileLock, &'static str> {
+        let path = self.dir.join(format!("{}.json.lock", id));
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => Ok(FileLock(path)),
+            Err(ref e) if e.kind() == ErrorKind::AlreadyExists => Err("Game is being saved by another writer"),
+            Err(_) => Err("Could not lock game")
+        }
+    }
+
+    fn write(&self, stored: &StoredGame) -> Result<(), &'static str> {
+        let json = serde_json::to_string(stored).map_err(|_| "Could not serialize game")?;
+
+        // write then rename so readers never see a partial document
+        let writer = WRITES.fetch_add(1, Ordering::SeqCst);
+        let tmp = self.dir.join(format!("{}.json.{}-{}.tmp", stored.id, process::id(), writer));
+        fs::write(&tmp, json).map_err(|_| "Could not write game")?;
+        fs::rename(&tmp, self.path(stored.id)).map_err(|_| {
+            let _ = fs::remove_file(&tmp);
+            "Could not write game"
+        })
+    }
+}
+
+impl GameStore for FileStore {
+    fn create(&mut self, game: GameDefinition) -> Result<StoredGame, &'static str> {
+        let mut id = self.ids()?.last().map_or(0, |id| id + 1);
+
+        // another process may take the same id first
+        loop {
+            if let Ok(_lock) = self.lock(id) {
+                if !self.path(id).exists() {
+                    let stored = StoredGame {
+                        id,
+                        version: 0,
+                        game
+                    };
+
+                    self.write(&stored)?;
+                    return Ok(stored);
+                }
+            }
+
+            id += 1;
+        }
+    }
+
+    fn load(&self, id: u64) -> Result<StoredGame, &'static str> {
+        let json = match fs::read_to_string(self.path(id)) {
+            Ok(j) => j,
+            Err(ref e) if e.kind() == ErrorKind::NotFound => return Err("Game not found"),
+            Err(_) => return Err("Could not read game")
+        };
+
+        serde_json::from_str(&json).map_err(|_| "Could not deserialize game")
+    }
+
+    fn save(&mut self, id: u64, game: GameDefinition, expected_version: u64) -> Result<StoredGame, &'static str> {
+        let _lock = self.lock(id)?;
+        let current = self.load(id)?;
+
+        if current.version != expected_version {
+            return Err("Game has changed since it was loaded");
+        }
+
+        let stored = StoredGame {
+            id,
+            version: current.version + 1,
+            game
+        };
+
+        self.write(&stored)?;
+        Ok(stored)
+    }
+
+    fn list_active(&self) -> Result<Vec<u64>, &'static str> {
+        let mut active = vec!();
+
+        for id in self.ids()? {
+            if is_active(&self.load(id)?.game) {
+                active.push(id);
+            }
+        }
+
+        Ok(active)
+    }
+}
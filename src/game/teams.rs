@@ -0,0 +1,174 @@
+use cards::card::PlayerCard;
+use game::game::{Game, Outcome};
+use game::history::Turn;
+use game::player_move::Move;
+
+/// players who win or lose together
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Team {
+    /// player ids, in seating order
+    pub players: Vec<u64>
+}
+
+/// the result of a finished partnership game
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TeamOutcome {
+    /// team numbers, in the order every member of the team went out
+    pub finishing_order: Vec<usize>,
+    /// points for each team, by team number
+    pub scores: Vec<u64>
+}
+
+/// a seat as seen by a player in a partnership game
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TeamSeat {
+    /// player id
+    pub id: u64,
+    /// the team the player is in
+    pub team: usize,
+    /// whether the player is a partner of the viewer
+    pub partner: bool,
+    /// number of cards held
+    pub remaining_cards: usize
+}
+
+/// a partnership game as seen by one player - partners' hands
+/// are hidden like everyone else's
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TeamView {
+    /// the player the view is for
+    pub player: u64,
+    /// the viewer's team
+    pub team: usize,
+    /// the viewer's hand
+    pub hand: Vec<PlayerCard>,
+    /// every seat at the table
+    pub seats: Vec<TeamSeat>,
+    /// the move on the table
+    pub last_move: Move,
+    /// who plays next
+    pub next_player: Option<u64>,
+    /// order of winners
+    pub winners: Vec<u64>,
+    /// teams whose players have all gone out, in order
+    pub finished_teams: Vec<usize>,
+    /// whether cards are reversed
+    pub reversed: bool,
+    /// moves made so far, without any hidden information
+    pub history: Vec<Turn>
+}
+
+impl TeamView {
+
+    /// build the view of a partnership game for `player_id`
+    pub fn new(game: &Game, player_id: u64) -> Result<TeamView, &'static str> {
+        let game_def = game.export();
+        let teams = &game_def.rules.teams;
+
+        let team = get_team(teams, player_id).ok_or("Player is not in a team")?;
+        let player = game.get_player(player_id).ok_or("Invalid player!")?;
+
+        let seats = game_def.players.iter().map(|p| {
+            let seat_team = get_team(teams, p.get_id()).ok_or("Player is not in a team")?;
+            Ok(TeamSeat {
+                id: p.get_id(),
+                team: seat_team,
+                partner: seat_team == team && p.get_id() != player_id,
+                remaining_cards: p.remaining_cards()
+            })
+        }).collect::<Result<Vec<TeamSeat>, &'static str>>()?;
+
+        let history = game_def.history.iter()
+            .map(|turn| Turn::new(turn.player, turn.cards.clone()))
+            .collect();
+
+        Ok(TeamView {
+            player: player_id,
+            team,
+            hand: player.get_hand(),
+            seats,
+            last_move: game.get_last_move(),
+            next_player: if game.is_finished() { None } else { game.get_next_player().map(|p| p.get_id()) },
+            finished_teams: get_finished_teams(teams, &game_def.winners),
+            winners: game_def.winners,
+            reversed: game_def.reversed,
+            history
+        })
+    }
+}
+
+/// pair each player with the one seated opposite, so that
+/// `player_ids[i]` partners `player_ids[i + n / 2]`
+pub fn seated_opposite(player_ids: &[u64]) -> Result<Vec<Team>, &'static str> {
+    if player_ids.len() < 4 || !player_ids.len().is_multiple_of(2) {
+        return Err("Partners need an even number of players, at least four");
+    }
+
+    let half = player_ids.len() / 2;
+
+    Ok((0..half).map(|i| Team { players: vec!(player_ids[i], player_ids[i + half]) }).collect())
+}
+
+/// check every player is in exactly one team, and every team
+/// member is playing
+pub fn check_teams(teams: &[Team], player_ids: &[u64]) -> Result<(), &'static str> {
+    let members: Vec<u64> = teams.iter().flat_map(|t| t.players.clone()).collect();
+
+    let all_seated = members.iter().all(|id| player_ids.contains(id));
+    let each_once = player_ids.iter().all(|id| members.iter().filter(|m| *m == id).count() == 1);
+
+    if !all_seated || !each_once || members.len() != player_ids.len() {
+        return Err("Every player must be in exactly one team");
+    }
+
+    Ok(())
+}
+
+/// the team number of a player
+pub fn get_team(teams: &[Team], player_id: u64) -> Option<usize> {
+    teams.iter().position(|t| t.players.contains(&player_id))
+}
+
+/// the first of the player's partners still in `in_play`
+pub fn get_partner_in_play(teams: &[Team], player_id: u64, in_play: &[u64]) -> Option<u64> {
+    let team = get_team(teams, player_id)?;
+
+    teams[team].players.iter()
+        .find(|id| **id != player_id && in_play.contains(id))
+        .cloned()
+}
+
+/// teams whose players have all gone out, in the order the last
+/// of each went out
+pub fn get_finished_teams(teams: &[Team], winners: &[u64]) -> Vec<usize> {
+    let mut finished: Vec<(usize, usize)> = teams.iter().enumerate().filter_map(|(i, team)| {
+        team.players.iter()
+            .map(|id| winners.iter().position(|w| w == id))
+            .collect::<Option<Vec<usize>>>()
+            .and_then(|places| places.into_iter().max())
+            .map(|last| (last, i))
+    }).collect();
+
+    finished.sort();
+    finished.into_iter().map(|(_, team)| team).collect()
+}
+
+/// score a finished game by team
+///
+/// each player scores a point for every player who finished
+/// behind them, and a team scores what its players score
+pub fn get_team_outcome(teams: &[Team], outcome: &Outcome) -> TeamOutcome {
+    let order = &outcome.finishing_order;
+
+    let scores = teams.iter().map(|team| {
+        team.players.iter()
+            .filter_map(|id| order.iter().position(|p| p == id))
+            .map(|place| (order.len() - 1 - place) as u64)
+            .sum()
+    }).collect();
+
+    TeamOutcome {
+        finishing_order: get_finished_teams(teams, order),
+        scores
+    }
+}
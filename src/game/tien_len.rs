@@ -0,0 +1,279 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use cards::card::{Card, PlayerCard};
+use cards::types::{Rank, Suit};
+use game::combinations::choose;
+use game::player_move::Move;
+
+/// the kinds of play in Tiến lên
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Combination {
+    /// one card
+    Single,
+    /// two cards of a rank
+    Pair,
+    /// three cards of a rank
+    Triple,
+    /// four cards of a rank - chops a single 2 or three
+    /// consecutive pairs
+    FourOfAKind,
+    /// three or more consecutive ranks, without 2s
+    Sequence(usize),
+    /// three or more consecutive pairs, without 2s - three pairs
+    /// chop a single 2, four or more chop a pair of 2s or four of a kind
+    ConsecutivePairs(usize)
+}
+
+/// a play in Tiến lên
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TienLenMove {
+    /// what kind of play this is
+    pub combination: Combination,
+    /// the cards, lowest first
+    pub cards: Vec<Card>
+}
+
+/// a play that a `Move` can hold by value - four of a kind, a
+/// sequence or consecutive pairs
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(from = "TienLenMove", into = "TienLenMove")]
+pub struct TienLenPlay {
+    combination: Combination,
+    // the cards, lowest first, as rank * 4 + suit - kept small
+    // so that moves stay cheap to copy
+    cards: [u8; MAX_PLAY],
+    len: u8
+}
+
+// the most cards in a play - twelve consecutive pairs
+const MAX_PLAY: usize = 24;
+
+const SUITS: [Suit; 4] = [Suit::Clubs, Suit::Hearts, Suit::Diamonds, Suit::Spades];
+
+const RANKS: [Rank; 13] = [Rank::Three, Rank::Four, Rank::Five, Rank::Six,
+    Rank::Seven, Rank::Eight, Rank::Nine, Rank::Ten, Rank::Jack,
+    Rank::Queen, Rank::King, Rank::Ace, Rank::Two];
+
+impl TienLenMove {
+
+    /// the play made by some cards, if they make one
+    ///
+    /// jokers are not used in Tiến lên
+    pub fn build(cards: &[PlayerCard]) -> Option<TienLenMove> {
+        let mut real = vec!();
+        for card in cards {
+            match *card {
+                PlayerCard::Card(c) => real.push(c),
+                _ => return None
+            }
+        }
+        real.sort_by(compare);
+
+        let by_rank = group_by_rank(&real);
+        let ranks: Vec<Rank> = by_rank.keys().cloned().collect();
+        let same_rank = ranks.len() == 1;
+
+        let combination = match real.len() {
+            0 => return None,
+            1 => Combination::Single,
+            2 if same_rank => Combination::Pair,
+            3 if same_rank => Combination::Triple,
+            4 if same_rank => Combination::FourOfAKind,
+            n if n >= 3 && by_rank.values().all(|g| g.len() == 1) && consecutive(&ranks) => Combination::Sequence(n),
+            n if n >= 6 && by_rank.values().all(|g| g.len() == 2) && consecutive(&ranks) => Combination::ConsecutivePairs(n / 2),
+            _ => return None
+        };
+
+        Some(TienLenMove { combination, cards: real })
+    }
+
+    /// the highest card, which decides between plays of the same kind
+    pub fn get_top_card(&self) -> Card {
+        *self.cards.last().unwrap()
+    }
+
+    /// whether this play can be made on top of `other`
+    pub fn beats(&self, other: &TienLenMove) -> bool {
+        let higher = compare(&self.get_top_card(), &other.get_top_card()) == Ordering::Greater;
+
+        if self.combination == other.combination {
+            return higher;
+        }
+
+        let twos = other.cards.iter().all(|c| c.rank == Rank::Two);
+
+        // chops, which beat 2s and each other regardless of kind
+        match (self.combination, other.combination) {
+            (Combination::ConsecutivePairs(3), Combination::Single) => twos,
+            (Combination::FourOfAKind, Combination::Single) => twos,
+            (Combination::FourOfAKind, Combination::ConsecutivePairs(3)) => true,
+            (Combination::ConsecutivePairs(n), Combination::Single) if n >= 4 => twos,
+            (Combination::ConsecutivePairs(n), Combination::Pair) if n >= 4 => twos,
+            (Combination::ConsecutivePairs(n), Combination::FourOfAKind) if n >= 4 => true,
+            (Combination::ConsecutivePairs(n), Combination::ConsecutivePairs(m)) => n > m && n >= 4,
+            _ => false
+        }
+    }
+
+    /// the play as a `Move`
+    pub fn to_move(&self) -> Move {
+        match (self.combination, &self.cards[..]) {
+            (Combination::Single, &[a]) => Move::Single(a),
+            (Combination::Pair, &[a, b]) => Move::Pair(a, b),
+            (Combination::Triple, &[a, b, c]) => Move::Prial(a, b, c),
+            _ => Move::TienLen(TienLenPlay::from(self.clone()))
+        }
+    }
+
+    /// the play a `Move` holds, if it is one
+    pub fn from_move(player_move: Move) -> Option<TienLenMove> {
+        match player_move {
+            Move::TienLen(play) => Some(play.into()),
+            Move::Pass | Move::FiveCardTrick(_) => None,
+            m => {
+                let cards: Vec<PlayerCard> = m.get_cards().into_iter().map(PlayerCard::Card).collect();
+                TienLenMove::build(&cards)
+            }
+        }
+    }
+}
+
+impl TienLenPlay {
+    /// what kind of play this is
+    pub fn get_combination(&self) -> Combination {
+        self.combination
+    }
+
+    /// the cards, lowest first
+    pub fn get_cards(&self) -> Vec<Card> {
+        self.cards[..self.len as usize].iter()
+            .map(|code| Card::new(RANKS[(code / 4) as usize], SUITS[(code % 4) as usize], false))
+            .collect()
+    }
+
+    /// the highest card
+    pub fn get_top_card(&self) -> Card {
+        *self.get_cards().last().unwrap()
+    }
+}
+
+impl From<TienLenMove> for TienLenPlay {
+    fn from(play: TienLenMove) -> TienLenPlay {
+        let len = play.cards.len().min(MAX_PLAY);
+        let mut cards = [0; MAX_PLAY];
+
+        for (code, card) in cards.iter_mut().zip(play.cards.iter()) {
+            *code = card.rank as u8 * 4 + card.suit as u8;
+        }
+
+        TienLenPlay { combination: play.combination, cards, len: len as u8 }
+    }
+}
+
+impl From<TienLenPlay> for TienLenMove {
+    fn from(play: TienLenPlay) -> TienLenMove {
+        TienLenMove { combination: play.combination, cards: play.get_cards() }
+    }
+}
+
+impl PartialEq for TienLenPlay {
+    fn eq(&self, other: &TienLenPlay) -> bool {
+        self.combination == other.combination && self.get_cards() == other.get_cards()
+    }
+}
+
+impl fmt::Debug for TienLenPlay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TienLenPlay")
+            .field("combination", &self.combination)
+            .field("cards", &self.get_cards())
+            .finish()
+    }
+}
+
+/// every play that can be made from a hand
+pub fn get_all(hand: &[PlayerCard]) -> Vec<Vec<PlayerCard>> {
+    let cards: Vec<PlayerCard> = hand.iter().filter(|c| matches!(**c, PlayerCard::Card(_))).cloned().collect();
+    let mut by_rank: BTreeMap<Rank, Vec<PlayerCard>> = BTreeMap::new();
+    for card in &cards {
+        by_rank.entry(card.to_card().rank).or_default().push(*card);
+    }
+
+    let mut moves = vec!();
+
+    for group in by_rank.values() {
+        for n in 1..5 {
+            moves.extend(choose(group, n));
+        }
+    }
+
+    // runs can't include 2s
+    let run_ranks = &RANKS[..12];
+
+    for start in 0..run_ranks.len() {
+        let mut singles: Vec<Vec<PlayerCard>> = vec!(vec!());
+        let mut pairs: Vec<Vec<PlayerCard>> = vec!(vec!());
+
+        for (length, rank) in run_ranks[start..].iter().enumerate() {
+            let group = match by_rank.get(rank) {
+                Some(g) => g,
+                None => break
+            };
+
+            singles = extend_runs(&singles, &choose(group, 1));
+            pairs = extend_runs(&pairs, &choose(group, 2));
+
+            if length + 1 >= 3 {
+                moves.extend(singles.clone());
+                moves.extend(pairs.clone());
+            }
+        }
+    }
+
+    moves
+}
+
+/// compare cards by rank, then suit, with ♠ < ♣ < ♦ < ♥
+pub fn compare(a: &Card, b: &Card) -> Ordering {
+    a.rank.cmp(&b.rank).then(suit_order(a.suit).cmp(&suit_order(b.suit)))
+}
+
+/// the position of a suit in Tiến lên, from ♠ (0) to ♥ (3)
+pub fn suit_order(suit: Suit) -> usize {
+    match suit {
+        Suit::Spades => 0,
+        Suit::Clubs => 1,
+        Suit::Diamonds => 2,
+        Suit::Hearts => 3
+    }
+}
+
+fn group_by_rank(cards: &[Card]) -> BTreeMap<Rank, Vec<Card>> {
+    let mut groups = BTreeMap::new();
+    for card in cards {
+        groups.entry(card.rank).or_insert_with(Vec::new).push(*card);
+    }
+    groups
+}
+
+fn consecutive(ranks: &[Rank]) -> bool {
+    if ranks.contains(&Rank::Two) {
+        return false;
+    }
+
+    ranks.windows(2).all(|w| w[1] as usize == w[0] as usize + 1)
+}
+
+fn extend_runs(runs: &[Vec<PlayerCard>], options: &[Vec<PlayerCard>]) -> Vec<Vec<PlayerCard>> {
+    let mut extended = vec!();
+    for run in runs {
+        for option in options {
+            let mut r = run.clone();
+            r.extend(option.iter().cloned());
+            extended.push(r);
+        }
+    }
+    extended
+}
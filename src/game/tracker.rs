@@ -0,0 +1,94 @@
+use cards::card::{Card, PlayerCard};
+use cards::types::Rank;
+use game::combinations;
+use game::game::GameDefinition;
+use game::player_move::Move;
+
+/// the cards a player has not yet seen, worked out from
+/// their own hand and the move history
+#[derive(Clone, Debug, PartialEq)]
+pub struct CardTracker {
+    unseen: Vec<PlayerCard>,
+    jokers: usize
+}
+
+impl CardTracker {
+
+    /// create a tracker from the point of view of `player_id`
+    pub fn new(game: &GameDefinition, player_id: u64) -> Result<CardTracker, &'static str> {
+        let player = match game.players.iter().find(|p| p.get_id() == player_id) {
+            Some(p) => p,
+            None => return Err("Invalid player!")
+        };
+
+        let played: Vec<PlayerCard> = game.history.iter()
+            .flat_map(|turn| turn.cards.clone())
+            .collect();
+
+        let mut unseen = vec!();
+        let mut jokers: usize = 0;
+
+        for card in game.rules.variant.get_deck(game.get_num_decks()).get_cards() {
+            match card {
+                PlayerCard::Joker(_) => jokers += 1,
+                c => unseen.push(c)
+            }
+        }
+
+        for card in player.get_hand().iter().chain(played.iter()) {
+            match *card {
+                PlayerCard::Card(c) => remove(&mut unseen, c),
+                PlayerCard::Joker(_) | PlayerCard::Wildcard(_) => jokers = jokers.saturating_sub(1)
+            }
+        }
+
+        let unseen = unseen.into_iter().map(|c| {
+            if game.reversed { c.reverse() } else { c }
+        }).collect();
+
+        Ok(CardTracker { unseen, jokers })
+    }
+
+    /// real cards that have not been seen, in the current card order
+    pub fn get_unseen(&self) -> Vec<PlayerCard> {
+        self.unseen.clone()
+    }
+
+    /// jokers that have not been seen
+    pub fn unseen_jokers(&self) -> usize {
+        self.jokers
+    }
+
+    /// how many cards of a rank have not been seen
+    pub fn count_unseen(&self, rank: Rank) -> usize {
+        self.unseen.iter().filter(|c| c.to_card().rank == rank).count()
+    }
+
+    /// the strongest real card that has not been seen
+    pub fn highest_unseen_single(&self) -> Option<PlayerCard> {
+        self.unseen.iter().fold(None, |best, card| match best {
+            Some(b) if b.to_card() > card.to_card() => Some(b),
+            _ => Some(*card)
+        })
+    }
+
+    /// whether the unseen cards, including any unseen jokers,
+    /// could make a move that beats `target`
+    pub fn can_unseen_beat(&self, target: Move) -> bool {
+        let mut cards = self.unseen.clone();
+        cards.extend((0..self.jokers).map(|n| PlayerCard::Joker(n as u64)));
+
+        combinations::can_beat(&cards, target)
+    }
+}
+
+fn remove(cards: &mut Vec<PlayerCard>, card: Card) {
+    let matches = |c: &PlayerCard| {
+        let c = c.to_card();
+        c.rank == card.rank && c.suit == card.suit
+    };
+
+    if let Some(pos) = cards.iter().position(matches) {
+        cards.remove(pos);
+    }
+}
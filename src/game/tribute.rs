@@ -0,0 +1,173 @@
+use std::cmp::Ordering;
+
+use cards::card::PlayerCard;
+use game::game::{Game, GameDefinition, Outcome};
+use game::player::Player;
+use game::rules::{Rules, Variant};
+use game::tien_len;
+
+/// cards handed over between the end of one game and the
+/// start of the next
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Tribute {
+    /// the player who finished lower last game
+    pub from: u64,
+    /// the player who finished higher last game
+    pub to: u64,
+    /// the best cards of `from`, taken when the exchange begins
+    pub given: Vec<PlayerCard>,
+    /// the cards `to` chose to send back - empty until returned
+    pub returned: Vec<PlayerCard>
+}
+
+/// a freshly dealt game waiting on its tributes
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Exchange {
+    game: GameDefinition,
+    tributes: Vec<Tribute>
+}
+
+impl Exchange {
+
+    /// take tributes for a dealt game from the finishing order of
+    /// the game before, as set by the game's rules
+    pub fn new(game: GameDefinition, previous: &Outcome) -> Result<Exchange, &'static str> {
+        if !game.history.is_empty() || game.round.has_started() {
+            return Err("The game has already started");
+        }
+
+        let order = &previous.finishing_order;
+        let same_players = order.len() == game.players.len()
+            && game.players.iter().all(|p| order.contains(&p.get_id()));
+
+        if !same_players {
+            return Err("The previous game had different players");
+        }
+
+        if game.rules.tribute.len() * 2 > order.len() {
+            return Err("Too many tributes for the number of players");
+        }
+
+        let mut players = game.players.clone();
+        let mut tributes = vec!();
+
+        for (place, count) in game.rules.tribute.iter().enumerate() {
+            let from = order[order.len() - 1 - place];
+            let to = order[place];
+
+            let given = get_best(&get_hand(&players, from), *count, &game.rules);
+            players = move_cards(&players, from, to, &given)?;
+
+            tributes.push(Tribute{ from, to, given, returned: vec!() });
+        }
+
+        Ok(Exchange {
+            game: GameDefinition{ players, ..game },
+            tributes
+        })
+    }
+
+    /// every tribute, in order from the winner down
+    pub fn get_tributes(&self) -> Vec<Tribute> {
+        self.tributes.clone()
+    }
+
+    /// the game as it stands, with tributes already given
+    pub fn get_game(&self) -> GameDefinition {
+        self.game.clone()
+    }
+
+    /// send back cards for the tribute `player_id` was given - as
+    /// many as were received, chosen from anywhere in the hand
+    pub fn return_cards(&self, player_id: u64, cards: Vec<PlayerCard>) -> Result<Exchange, &'static str> {
+        let index = self.tributes.iter()
+            .position(|t| t.to == player_id && t.returned.is_empty())
+            .ok_or("No cards are owed by this player")?;
+
+        let tribute = &self.tributes[index];
+
+        if cards.len() != tribute.given.len() {
+            return Err("Return as many cards as were given");
+        }
+
+        let players = move_cards(&self.game.players, player_id, tribute.from, &cards)?;
+
+        let mut tributes = self.tributes.clone();
+        tributes[index].returned = cards;
+
+        Ok(Exchange {
+            game: GameDefinition{ players, ..self.game.clone() },
+            tributes
+        })
+    }
+
+    /// whether every tribute has been returned
+    pub fn is_complete(&self) -> bool {
+        self.tributes.iter().all(|t| t.returned.len() == t.given.len())
+    }
+
+    /// start the game once every tribute has been returned
+    ///
+    /// who leads and any instant wins are worked out from the
+    /// hands after the exchange
+    pub fn start(&self) -> Result<GameDefinition, &'static str> {
+        if !self.is_complete() {
+            return Err("Every tribute must be returned before play starts");
+        }
+
+        let player_ids = self.game.players.iter().map(|p| p.get_id()).collect();
+        let hands = self.game.players.iter().map(|p| p.get_hand()).collect();
+
+        let mut game = Game::setup_with_hands(player_ids, hands, self.game.rules.clone())?;
+        game.setup.redeals = self.game.setup.redeals;
+        game.setup.num_decks = self.game.setup.num_decks;
+        game.setup.seed = self.game.setup.seed;
+        game.setup.tributes = self.tributes.clone();
+        Ok(game)
+    }
+}
+
+// jokers beat everything, then cards in the variant's order
+fn compare(a: &PlayerCard, b: &PlayerCard, rules: &Rules) -> Ordering {
+    match (*a, *b) {
+        (PlayerCard::Joker(_), PlayerCard::Joker(_)) => Ordering::Equal,
+        (PlayerCard::Joker(_), _) => Ordering::Greater,
+        (_, PlayerCard::Joker(_)) => Ordering::Less,
+        (x, y) => match rules.variant {
+            Variant::PusoyDos => x.to_card().partial_cmp(&y.to_card()).unwrap_or(Ordering::Equal),
+            Variant::TienLen => tien_len::compare(&x.to_card(), &y.to_card())
+        }
+    }
+}
+
+fn get_best(hand: &[PlayerCard], count: usize, rules: &Rules) -> Vec<PlayerCard> {
+    let mut sorted = hand.to_vec();
+    sorted.sort_by(|a, b| compare(b, a, rules));
+    sorted.truncate(count);
+    sorted
+}
+
+fn get_hand(players: &[Player], id: u64) -> Vec<PlayerCard> {
+    players.iter().find(|p| p.get_id() == id).map(|p| p.get_hand()).unwrap_or_default()
+}
+
+fn move_cards(players: &[Player], from: u64, to: u64, cards: &[PlayerCard]) -> Result<Vec<Player>, &'static str> {
+    let mut remaining = get_hand(players, from);
+
+    for card in cards {
+        let pos = remaining.iter().position(|c| c == card).ok_or("Cannot return cards you do not have")?;
+        remaining.remove(pos);
+    }
+
+    Ok(players.iter().map(|p| {
+        if p.get_id() == from {
+            p.set_hand(remaining.clone())
+        } else if p.get_id() == to {
+            let mut hand = p.get_hand();
+            hand.extend_from_slice(cards);
+            p.set_hand(hand)
+        } else {
+            p.clone()
+        }
+    }).collect())
+}
@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+
+use cards::card::{Card, PlayerCard};
+use cards::types::{Rank, Suit};
+use game::game::GameDefinition;
+use game::player_move::Move;
+
+/// something wrong with a game state
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Violation {
+    /// held and played cards do not add up to whole decks
+    CardCount {
+        /// cards expected for the number of decks in play
+        expected: usize,
+        /// cards held or played
+        found: usize
+    },
+    /// a card appears more often than the decks allow
+    DuplicateCard(Card),
+    /// more jokers are held or played than the decks allow
+    TooManyJokers(usize),
+    /// two players share an id
+    DuplicatePlayer(u64),
+    /// the round or winners name a player who is not in the game
+    UnknownPlayer(u64),
+    /// the player due to play is not in the round
+    CurrentPlayerNotInRound(u64),
+    /// a player with no cards is still in the round
+    FinishedPlayerInRound(u64),
+    /// a player with cards left is missing from the round
+    PlayerMissingFromRound(u64),
+    /// a winner still has cards
+    WinnerHasCards(u64),
+    /// a player is listed as a winner more than once
+    DuplicateWinner(u64),
+    /// a player with no cards is not listed as a winner
+    FinishedPlayerNotWinner(u64),
+    /// a card in a hand does not match the reversed flag
+    HandNotOriented(u64),
+    /// the last move does not match the reversed flag
+    LastMoveNotOriented,
+    /// the pass count is out of range for the round
+    InvalidPassCount(i64)
+}
+
+/// check a game state for consistency, returning every problem found
+///
+/// card conservation counts the cards in hands and in the move
+/// history, so a state saved without its history will only be
+/// checked for cards held more often than the decks allow
+pub fn validate(game: &GameDefinition) -> Vec<Violation> {
+    let mut violations = check_players(game);
+    violations.extend(check_cards(game));
+    violations.extend(check_round(game));
+    violations.extend(check_winners(game));
+    violations.extend(check_orientation(game));
+    violations
+}
+
+fn check_players(game: &GameDefinition) -> Vec<Violation> {
+    let mut seen = vec!();
+    let mut violations = vec!();
+
+    for player in &game.players {
+        let id = player.get_id();
+        if seen.contains(&id) {
+            violations.push(Violation::DuplicatePlayer(id));
+        } else {
+            seen.push(id);
+        }
+    }
+
+    violations
+}
+
+fn check_cards(game: &GameDefinition) -> Vec<Violation> {
+    let mut counts: BTreeMap<(Rank, Suit), usize> = BTreeMap::new();
+    let mut jokers = 0;
+
+    let held = game.players.iter().flat_map(|p| p.get_hand());
+    let played = game.history.iter().flat_map(|t| t.cards.clone());
+
+    for card in held.chain(played) {
+        match card {
+            PlayerCard::Card(c) => *counts.entry((c.rank, c.suit)).or_insert(0) += 1,
+            PlayerCard::Wildcard(_) | PlayerCard::Joker(_) => jokers += 1
+        }
+    }
+
+    let found = jokers + counts.values().sum::<usize>();
+    let decks = game.get_num_decks();
+    let deck_size = game.rules.variant.deck_size();
+    let mut violations = vec!();
+
+    // without a history the cards already played are unknown
+    let complete = !game.history.is_empty() || !game.round.has_started();
+    if complete && found != decks * deck_size {
+        violations.push(Violation::CardCount { expected: decks * deck_size, found });
+    }
+
+    for (&(rank, suit), &count) in &counts {
+        if count > decks {
+            violations.push(Violation::DuplicateCard(Card::new(rank, suit, false)));
+        }
+    }
+
+    if jokers > decks * game.rules.variant.jokers_per_deck() {
+        violations.push(Violation::TooManyJokers(jokers));
+    }
+
+    violations
+}
+
+fn check_round(game: &GameDefinition) -> Vec<Violation> {
+    let round = game.round.export();
+    let ids: Vec<u64> = game.players.iter().map(|p| p.get_id()).collect();
+    let mut violations = vec!();
+
+    for id in &round.players {
+        if !ids.contains(id) {
+            violations.push(Violation::UnknownPlayer(*id));
+        }
+    }
+
+    if !round.players.contains(&round.current_player) {
+        violations.push(Violation::CurrentPlayerNotInRound(round.current_player));
+    }
+
+    for player in &game.players {
+        let id = player.get_id();
+        let in_round = round.players.contains(&id);
+
+        if player.remaining_cards() == 0 && in_round {
+            violations.push(Violation::FinishedPlayerInRound(id));
+        }
+
+        if player.remaining_cards() > 0 && !in_round {
+            violations.push(Violation::PlayerMissingFromRound(id));
+        }
+    }
+
+    // a player going out leaves the count at -1
+    if round.pass_count < -1 || round.pass_count >= round.players.len() as i64 {
+        violations.push(Violation::InvalidPassCount(round.pass_count));
+    }
+
+    violations
+}
+
+fn check_winners(game: &GameDefinition) -> Vec<Violation> {
+    let mut violations = vec!();
+
+    for (i, id) in game.winners.iter().enumerate() {
+        if game.winners[..i].contains(id) {
+            violations.push(Violation::DuplicateWinner(*id));
+        }
+
+        match game.players.iter().find(|p| p.get_id() == *id) {
+            Some(p) if p.remaining_cards() > 0 && !won_on_deal(game, *id) => violations.push(Violation::WinnerHasCards(*id)),
+            Some(_) => (),
+            None => violations.push(Violation::UnknownPlayer(*id))
+        }
+    }
+
+    for player in &game.players {
+        if player.remaining_cards() == 0 && !game.winners.contains(&player.get_id()) {
+            violations.push(Violation::FinishedPlayerNotWinner(player.get_id()));
+        }
+    }
+
+    violations
+}
+
+// a player who wins on the deal keeps their cards
+fn won_on_deal(game: &GameDefinition, id: u64) -> bool {
+    game.setup.instant_wins.iter().any(|claim| claim.player == id)
+}
+
+fn check_orientation(game: &GameDefinition) -> Vec<Violation> {
+    let mut violations = vec!();
+
+    for player in &game.players {
+        let oriented = player.get_hand().iter().all(|c| match *c {
+            PlayerCard::Card(card) | PlayerCard::Wildcard(card) => card.reversed == game.reversed,
+            PlayerCard::Joker(_) => true
+        });
+
+        if !oriented {
+            violations.push(Violation::HandNotOriented(player.get_id()));
+        }
+    }
+
+    let last_move = game.round.get_last_move();
+    if last_move != Move::Pass && last_move.get_top_card().reversed != game.reversed {
+        violations.push(Violation::LastMoveNotOriented);
+    }
+
+    violations
+}
@@ -7,12 +7,29 @@ extern crate serde_derive;
 
 extern crate serde;
 extern crate serde_json;
+#[cfg(feature = "signing")]
+extern crate hmac;
+#[cfg(feature = "signing")]
+extern crate sha2;
+#[cfg(feature = "wasm")]
+extern crate wasm_bindgen;
 
 ///! generic(ish) card module
 #[macro_use]
 pub mod cards;
 ///! game specific module
 pub mod game;
+///! competitions made up of many games
+pub mod tournament;
+///! javascript bindings
+#[cfg(feature = "wasm")]
+pub mod wasm;
+///! c interface
+#[cfg(feature = "ffi")]
+pub mod ffi;
+///! svg images of games
+#[cfg(feature = "svg")]
+pub mod svg;
 
 #[cfg(test)]
 mod tests;
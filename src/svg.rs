@@ -0,0 +1,173 @@
+//! svg images of hands, moves and tables
+//!
+//! images are plain svg text. suits are drawn as shapes rather than
+//! glyphs, and text only uses the generic sans-serif family, so no
+//! fonts or other files are needed to view them
+
+use cards::card::{Card, PlayerCard};
+use cards::types::{Colour, Rank, Suit};
+use game::game::{Game, GameDefinition};
+use game::player_move::Move;
+
+const CARD_WIDTH: i64 = 60;
+const CARD_HEIGHT: i64 = 84;
+// how far each card in a hand overlaps the last
+const CARD_SPACING: i64 = 30;
+const MARGIN: i64 = 10;
+const SEAT_WIDTH: i64 = 120;
+
+/// draw a hand of cards, fanned left to right
+pub fn render_hand(cards: &[PlayerCard]) -> String {
+    let width = fan_width(cards.len()) + MARGIN * 2;
+    let height = CARD_HEIGHT + MARGIN * 2;
+
+    document(width, height, &fan(cards, MARGIN, MARGIN))
+}
+
+/// draw the cards of a move - a pass is drawn as the word "Pass"
+pub fn render_move(player_move: Move) -> String {
+    let cards = player_move.get_cards();
+    let width = fan_width(cards.len().max(2)) + MARGIN * 2;
+    let height = CARD_HEIGHT + MARGIN * 2;
+
+    document(width, height, &draw_move(player_move, MARGIN, MARGIN, width))
+}
+
+/// draw a whole table - every seat with its card count, who is to
+/// play, who has finished, the move to beat and whether the card
+/// order is reversed
+pub fn render_table(game: &GameDefinition) -> Result<String, &'static str> {
+    let next = Game::load(game.clone())?.get_next_player().map(|p| p.get_id());
+
+    let width = (SEAT_WIDTH * game.players.len() as i64).max(fan_width(5) + MARGIN * 2);
+    let height = 240;
+    let mut body = String::new();
+
+    for (i, player) in game.players.iter().enumerate() {
+        let x = i as i64 * SEAT_WIDTH + MARGIN;
+        let id = player.get_id();
+        // the player to move is outlined in gold
+        let (stroke, stroke_width) = if Some(id) == next { ("#e0a000", 3) } else { ("#335533", 1) };
+
+        body.push_str(&format!("<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"70\" rx=\"6\" fill=\"#1f6f3f\" stroke=\"{}\" stroke-width=\"{}\"/>",
+                               x, MARGIN, SEAT_WIDTH - MARGIN * 2, stroke, stroke_width));
+        body.push_str(&text(x + 8, MARGIN + 20, 14, "#ffffff", &format!("Player {}", id)));
+        body.push_str(&text(x + 8, MARGIN + 40, 12, "#ffffff", &format!("{} cards", player.remaining_cards())));
+
+        if let Some(place) = game.winners.iter().position(|w| *w == id) {
+            body.push_str(&text(x + 8, MARGIN + 60, 12, "#ffd966", &format!("Finished {}", ordinal(place + 1))));
+        } else if Some(id) == next {
+            body.push_str(&text(x + 8, MARGIN + 60, 12, "#ffd966", "To play"));
+        }
+    }
+
+    body.push_str(&draw_move(game.round.get_last_move(), MARGIN, 100, width));
+
+    if game.reversed {
+        body.push_str(&text(MARGIN, height - MARGIN, 14, "#ffd966", "Reversed"));
+    }
+
+    Ok(document(width, height, &format!("<rect width=\"{}\" height=\"{}\" fill=\"#0b4d2a\"/>{}", width, height, body)))
+}
+
+fn draw_move(player_move: Move, x: i64, y: i64, width: i64) -> String {
+    match player_move {
+        Move::Pass => text(width / 2 - 20, y + CARD_HEIGHT / 2, 18, "#888888", "Pass"),
+        m => {
+            let cards: Vec<PlayerCard> = m.get_cards().into_iter().map(PlayerCard::Card).collect();
+            fan(&cards, x, y)
+        }
+    }
+}
+
+
+
+fn fan_width(cards: usize) -> i64 {
+    if cards == 0 {
+        return CARD_WIDTH;
+    }
+
+    CARD_WIDTH + (cards as i64 - 1) * CARD_SPACING
+}
+
+fn fan(cards: &[PlayerCard], x: i64, y: i64) -> String {
+    cards.iter().enumerate()
+        .map(|(i, card)| draw_card(card, x + i as i64 * CARD_SPACING, y))
+        .collect()
+}
+
+fn draw_card(card: &PlayerCard, x: i64, y: i64) -> String {
+    let (face, border) = match *card {
+        PlayerCard::Card(c) => (card_face(c), ""),
+        // a joker played as a card gets a dashed border
+        PlayerCard::Wildcard(c) => (card_face(c), " stroke-dasharray=\"4 3\""),
+        PlayerCard::Joker(_) => (text(8, 48, 12, "#7a1fa2", "JOKER"), "")
+    };
+
+    format!("<g transform=\"translate({},{})\"><rect width=\"{}\" height=\"{}\" rx=\"5\" fill=\"#ffffff\" stroke=\"#333333\"{}/>{}</g>",
+            x, y, CARD_WIDTH, CARD_HEIGHT, border, face)
+}
+
+fn card_face(card: Card) -> String {
+    let colour = match card.colour {
+        Colour::Red => "#c00000",
+        Colour::Black => "#000000"
+    };
+
+    format!("{}<g transform=\"translate(6,26)\">{}</g><g transform=\"translate(26,48)\">{}</g>",
+            text(5, 18, 16, colour, rank_label(card.rank)),
+            suit_shape(card.suit, colour, 0.5),
+            suit_shape(card.suit, colour, 1.0))
+}
+
+// a suit drawn in a 20 by 20 box, scaled by `scale`
+fn suit_shape(suit: Suit, colour: &str, scale: f64) -> String {
+    let shape = match suit {
+        Suit::Diamonds => "<path d=\"M10 0 L19 10 L10 20 L1 10 Z\"/>".to_string(),
+        Suit::Hearts => "<path d=\"M10 19 L2 10 A5 5 0 0 1 10 4 A5 5 0 0 1 18 10 Z\"/>".to_string(),
+        Suit::Spades => "<path d=\"M10 1 L18 11 A4.5 4.5 0 0 1 11 15 L13 20 L7 20 L9 15 A4.5 4.5 0 0 1 2 11 Z\"/>".to_string(),
+        Suit::Clubs => "<circle cx=\"10\" cy=\"5\" r=\"4.5\"/><circle cx=\"5\" cy=\"12\" r=\"4.5\"/><circle cx=\"15\" cy=\"12\" r=\"4.5\"/><path d=\"M9 12 L11 12 L13 20 L7 20 Z\"/>".to_string()
+    };
+
+    format!("<g transform=\"scale({})\" fill=\"{}\">{}</g>", scale, colour, shape)
+}
+
+fn rank_label(rank: Rank) -> &'static str {
+    match rank {
+        Rank::Three => "3",
+        Rank::Four => "4",
+        Rank::Five => "5",
+        Rank::Six => "6",
+        Rank::Seven => "7",
+        Rank::Eight => "8",
+        Rank::Nine => "9",
+        Rank::Ten => "10",
+        Rank::Jack => "J",
+        Rank::Queen => "Q",
+        Rank::King => "K",
+        Rank::Ace => "A",
+        Rank::Two => "2"
+    }
+}
+
+fn ordinal(place: usize) -> String {
+    let suffix = match (place % 10, place % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th"
+    };
+
+    format!("{}{}", place, suffix)
+}
+
+fn text(x: i64, y: i64, size: i64, colour: &str, content: &str) -> String {
+    format!("<text x=\"{}\" y=\"{}\" font-family=\"sans-serif\" font-size=\"{}\" fill=\"{}\">{}</text>",
+            x, y, size, colour, content)
+}
+
+fn document(width: i64, height: i64, body: &str) -> String {
+    format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">{2}</svg>",
+            width, height, body)
+}
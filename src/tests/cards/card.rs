@@ -1,5 +1,5 @@
 use cards::types::*;
-use cards::card::{ PlayerCard, Card };
+use cards::card::{ PlayerCard, Card, parse_card, parse_cards };
 
 #[test]
 pub fn card_macro_makes_it_easy_to_declare_card(){
@@ -111,3 +111,30 @@ pub fn reversed_card_comparison(){
     assert!(club > diamond);
 }
 
+
+#[test]
+pub fn cards_can_be_parsed(){
+    assert_eq!(parse_card("3C"), Ok(card!(Three, Clubs)));
+    assert_eq!(parse_card("10h"), Ok(card!(Ten, Hearts)));
+    assert_eq!(parse_card("TD"), Ok(card!(Ten, Diamonds)));
+    assert_eq!(parse_card("Q♠"), Ok(card!(Queen, Spades)));
+    assert_eq!(parse_card("*2S"), Ok(wildcard!(Two, Spades)));
+    assert_eq!(parse_card("JK"), Ok(PlayerCard::Joker(0)));
+    assert_eq!(parse_card("jk1"), Ok(PlayerCard::Joker(1)));
+    assert_eq!("AS".parse::<PlayerCard>(), Ok(card!(Ace, Spades)));
+}
+
+#[test]
+pub fn bad_cards_are_not_parsed(){
+    assert!(parse_card("").is_err());
+    assert!(parse_card("1C").is_err());
+    assert!(parse_card("3X").is_err());
+    assert!(parse_card("JKX").is_err());
+}
+
+#[test]
+pub fn lists_of_cards_can_be_parsed(){
+    assert_eq!(parse_cards("3C, 3D 3H"), Ok(vec!(card!(Three, Clubs), card!(Three, Diamonds), card!(Three, Hearts))));
+    assert_eq!(parse_cards(""), Ok(vec!()));
+    assert!(parse_cards("3C XX").is_err());
+}
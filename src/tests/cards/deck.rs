@@ -1,4 +1,6 @@
 use cards::deck::*;
+use cards::card::*;
+use cards::types::*;
 
 #[test]
 pub fn it_gets_a_full_deck_including_jokers(){
@@ -28,3 +30,15 @@ pub fn decks_can_be_dealt(){
     assert_eq!(dealt.len(), 4);
     assert_eq!(dealt[0].len(), 14);
 }
+
+#[test]
+pub fn a_seed_always_gives_the_same_order(){
+    // pinned so a seed deals the same cards on every platform
+    // and in every version
+    let mut deck = Deck::new();
+    deck.shuffle_seeded(42);
+
+    assert_eq!(&deck.get_cards()[..6], &[card!(Seven, Clubs), card!(Queen, Spades), card!(Ace, Hearts),
+                                          card!(King, Clubs), card!(Three, Spades), card!(Ten, Clubs)]);
+    assert_eq!(deck.count(), 54);
+}
@@ -1,2 +1,3 @@
 pub mod deck;
 pub mod card;
+pub mod render;
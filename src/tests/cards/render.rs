@@ -0,0 +1,43 @@
+use cards::card::*;
+use cards::types::*;
+use cards::render::*;
+
+#[test]
+pub fn cards_can_be_shown_as_ascii(){
+    assert_eq!(render_card(&card!(Three, Clubs), Style::Ascii), "3C");
+    assert_eq!(render_card(&card!(Ten, Hearts), Style::Ascii), "10H");
+    assert_eq!(render_card(&wildcard!(Two, Spades), Style::Ascii), "*2S");
+    assert_eq!(render_card(&PlayerCard::Joker(0), Style::Ascii), "JK");
+    assert_eq!(render_card(&PlayerCard::Joker(1), Style::Ascii), "JK1");
+}
+
+#[test]
+pub fn ascii_cards_can_be_parsed_back(){
+    let cards = vec!(card!(Ten, Diamonds), wildcard!(Ace, Hearts), card!(King, Spades), PlayerCard::Joker(0), PlayerCard::Joker(1));
+
+    assert_eq!(parse_cards(&render_cards(&cards, Style::Ascii)), Ok(cards));
+}
+
+#[test]
+pub fn unicode_cards_match_display(){
+    let card = Card::new(Rank::Queen, Suit::Hearts, false);
+
+    assert_eq!(render_card(&PlayerCard::Card(card), Style::Unicode), format!("{}", card));
+}
+
+#[test]
+pub fn cards_can_be_shown_as_playing_card_characters(){
+    assert_eq!(render_card(&card!(Ace, Spades), Style::PlayingCard), "\u{1F0A1}");
+    assert_eq!(render_card(&card!(Three, Clubs), Style::PlayingCard), "\u{1F0D3}");
+    assert_eq!(render_card(&card!(Ten, Hearts), Style::PlayingCard), "\u{1F0BA}");
+    assert_eq!(render_card(&card!(Queen, Diamonds), Style::PlayingCard), "\u{1F0CD}");
+    assert_eq!(render_card(&card!(King, Clubs), Style::PlayingCard), "\u{1F0DE}");
+}
+
+#[test]
+pub fn cards_can_be_named(){
+    assert_eq!(render_card(&card!(Three, Clubs), Style::Name(Language::English)), "Three of Clubs");
+    assert_eq!(render_card(&card!(Queen, Hearts), Style::Name(Language::Filipino)), "Reyna ng Puso");
+    assert_eq!(render_card(&card!(Ace, Spades), Style::Name(Language::Chinese)), "黑桃A");
+    assert_eq!(render_card(&wildcard!(Two, Diamonds), Style::Name(Language::English)), "Two of Diamonds (Joker)");
+}
@@ -0,0 +1,93 @@
+use std::ptr;
+
+use ffi::*;
+use game::errors;
+
+unsafe fn get_game() -> *mut PdGame {
+    let players = [1, 2, 3, 4];
+    let mut game = ptr::null_mut();
+
+    assert_eq!(pd_game_new(players.as_ptr(), 4, 1, 42, &mut game), PD_OK);
+    game
+}
+
+#[test]
+pub fn a_game_can_be_dealt_and_played(){
+    unsafe {
+        let game = get_game();
+
+        let mut first = 0;
+        assert_eq!(pd_game_next_player(game, &mut first), PD_OK);
+
+        let three_of_clubs = [0];
+        assert_eq!(pd_game_play(game, first, three_of_clubs.as_ptr(), 1), PD_OK);
+
+        let mut last = [0; 5];
+        let mut last_len = 0;
+        assert_eq!(pd_game_last_move(game, last.as_mut_ptr(), 5, &mut last_len), PD_OK);
+        assert_eq!(&last[..last_len], &[0]);
+
+        pd_game_free(game);
+    }
+}
+
+#[test]
+pub fn a_game_without_players_is_not_dealt(){
+    let players = [1];
+    let mut game = ptr::null_mut();
+
+    unsafe {
+        assert_eq!(pd_game_new(players.as_ptr(), 0, 1, 42, &mut game), PD_ERR_SETUP_FAILED);
+        assert_eq!(pd_game_new(players.as_ptr(), 1, 0, 42, &mut game), PD_ERR_SETUP_FAILED);
+    }
+
+    assert!(game.is_null());
+}
+
+#[test]
+pub fn bad_moves_have_their_own_codes(){
+    unsafe {
+        let game = get_game();
+        let mut first = 0;
+        pd_game_next_player(game, &mut first);
+
+        let bad_code = [200];
+        let not_a_move = [0, 4];
+        let three_of_clubs = [0];
+        let other = if first == 1 { 2 } else { 1 };
+
+        assert_eq!(pd_game_play(game, first, bad_code.as_ptr(), 1), PD_ERR_INVALID_CARD);
+        assert_eq!(pd_game_play(game, first, not_a_move.as_ptr(), 2), PD_ERR_INVALID_MOVE);
+        assert_eq!(pd_game_play(game, other, three_of_clubs.as_ptr(), 1), PD_ERR_CARDS_NOT_HELD);
+        assert_eq!(pd_game_play(game, 99, three_of_clubs.as_ptr(), 1), PD_ERR_INVALID_PLAYER);
+        assert_eq!(pd_game_play(ptr::null_mut(), first, three_of_clubs.as_ptr(), 1), PD_ERR_NULL_POINTER);
+
+        pd_game_free(game);
+    }
+}
+
+#[test]
+pub fn every_move_error_has_a_code(){
+    assert_eq!(error_code(errors::WON_ON_DEAL), PD_ERR_WON_ON_DEAL);
+    assert_eq!(error_code(errors::MOVE_NOT_ALLOWED), PD_ERR_MOVE_NOT_ALLOWED);
+    assert_eq!(error_code(errors::HIGHEST_SINGLE_REQUIRED), PD_ERR_HIGHEST_SINGLE_REQUIRED);
+    assert_eq!(error_code(errors::LAST_CARD_NOT_ANNOUNCED), PD_ERR_LAST_CARD_NOT_ANNOUNCED);
+    assert_eq!(error_code(errors::LAST_CARD_WRONGLY_ANNOUNCED), PD_ERR_LAST_CARD_WRONGLY_ANNOUNCED);
+    assert_eq!(error_code("Something else"), PD_ERR_OTHER);
+}
+
+#[test]
+pub fn an_announcement_can_be_passed_on(){
+    unsafe {
+        let game = get_game();
+        let mut first = 0;
+        pd_game_next_player(game, &mut first);
+
+        // announcing is only checked when the rules ask for it
+        let three_of_clubs = [0];
+        assert_eq!(pd_game_play_announcing(game, first, three_of_clubs.as_ptr(), 1, true), PD_OK);
+        assert_eq!(pd_game_play_announcing(ptr::null_mut(), first, three_of_clubs.as_ptr(), 1, false), PD_ERR_NULL_POINTER);
+
+        pd_game_free(game);
+    }
+}
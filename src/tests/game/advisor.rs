@@ -0,0 +1,85 @@
+use game::game::{ Game, GameDefinition };
+use game::player_move::{Move, build_move};
+use cards::card::*;
+use cards::types::*;
+use game::player::Player;
+use game::round::Round;
+
+fn get_game(hand: Vec<PlayerCard>, last_move: Move) -> Game {
+    let player1 = Player::new(0).set_hand(hand);
+    let player2 = Player::new(1).set_hand(vec!(card!(Three, Diamonds), card!(Ace, Hearts)));
+
+    Game::load(GameDefinition::new(
+        vec!(player1, player2),
+        Round::new(vec!(0, 1), 0, last_move, 0, false),
+        vec!(),
+        false)).unwrap()
+}
+
+#[test]
+pub fn the_lowest_beating_single_is_suggested_first(){
+    let nine = build_move(vec!(card!(Nine, Spades))).unwrap();
+    let game = get_game(vec!(card!(Ten, Clubs), card!(King, Hearts), card!(Four, Diamonds)), nine);
+
+    let hints = game.get_hints();
+
+    assert_eq!(hints[0].cards, vec!(card!(Ten, Clubs)));
+    assert!(hints[0].reasons.contains(&"lowest single that beats ♠9".to_string()));
+}
+
+#[test]
+pub fn passing_is_explained_when_nothing_beats_the_table(){
+    let two = build_move(vec!(card!(Two, Hearts))).unwrap();
+    let game = get_game(vec!(card!(Ten, Clubs), card!(Four, Diamonds)), two);
+
+    let hints = game.get_hints();
+
+    assert_eq!(hints.len(), 1);
+    assert_eq!(hints[0].cards, vec!());
+    assert_eq!(hints[0].reasons, vec!("nothing in your hand beats ♥2".to_string()));
+}
+
+#[test]
+pub fn playing_only_twos_is_explained(){
+    let ace = build_move(vec!(card!(Ace, Spades))).unwrap();
+    let game = get_game(vec!(card!(Two, Clubs), card!(Four, Diamonds), card!(Six, Diamonds)), ace);
+
+    let hints = game.get_hints();
+
+    assert_eq!(hints[0].cards, vec!(card!(Two, Clubs)));
+    assert!(hints[0].reasons.contains(&"only 2s can beat this".to_string()));
+}
+
+#[test]
+pub fn suggestions_avoid_breaking_up_a_flush(){
+    let five = build_move(vec!(card!(Five, Spades))).unwrap();
+    let game = get_game(vec!(
+        card!(Six, Hearts), card!(Eight, Hearts), card!(Ten, Hearts),
+        card!(Queen, Hearts), card!(King, Hearts), card!(Seven, Clubs)), five);
+
+    let hints = game.get_hints();
+
+    assert_eq!(hints[0].cards, vec!(card!(Seven, Clubs)));
+    assert!(hints[0].reasons.contains(&"keeps your flush intact".to_string()));
+}
+
+#[test]
+pub fn splitting_a_pair_is_discouraged(){
+    let five = build_move(vec!(card!(Five, Spades))).unwrap();
+    let game = get_game(vec!(card!(Six, Hearts), card!(Six, Clubs), card!(Seven, Clubs)), five);
+
+    let hints = game.get_hints();
+
+    assert_eq!(hints[0].cards, vec!(card!(Seven, Clubs)));
+    assert!(hints.iter().any(|h| h.reasons.contains(&"splits your pair of 6s".to_string())));
+}
+
+#[test]
+pub fn winning_plays_come_first(){
+    let game = get_game(vec!(card!(Nine, Hearts), card!(Nine, Clubs)), Move::Pass);
+
+    let hints = game.get_hints();
+
+    assert_eq!(hints[0].cards.len(), 2);
+    assert!(hints[0].reasons.contains(&"plays your last cards".to_string()));
+}
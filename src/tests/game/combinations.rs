@@ -0,0 +1,85 @@
+use cards::card::*;
+use cards::types::*;
+use game::combinations::*;
+use game::player_move::{Move, TrickType, build_move};
+
+#[test]
+pub fn every_card_is_a_single(){
+    let hand = vec!(card!(Three, Clubs), card!(Four, Hearts), PlayerCard::Joker(0));
+
+    assert_eq!(get_singles(&hand), vec!(vec!(card!(Three, Clubs)), vec!(card!(Four, Hearts))));
+}
+
+#[test]
+pub fn pairs_and_prials_come_from_matching_ranks(){
+    let hand = vec!(card!(Nine, Clubs), card!(Nine, Hearts), card!(Nine, Spades), card!(Four, Hearts));
+
+    assert_eq!(get_pairs(&hand).len(), 3);
+    assert_eq!(get_prials(&hand), vec!(vec!(card!(Nine, Clubs), card!(Nine, Hearts), card!(Nine, Spades))));
+}
+
+#[test]
+pub fn five_card_tricks_are_found(){
+    let hand = vec!(
+        card!(Three, Clubs), card!(Four, Clubs), card!(Five, Clubs),
+        card!(Six, Clubs), card!(Seven, Hearts), card!(Jack, Clubs));
+
+    let tricks: Vec<Move> = get_five_card_tricks(&hand).into_iter()
+        .map(|c| build_move(c).unwrap())
+        .collect();
+
+    let count = |trick_type| tricks.iter().filter(|m| match **m {
+        Move::FiveCardTrick(t) => t.trick_type == trick_type,
+        _ => false
+    }).count();
+
+    assert_eq!(count(TrickType::Straight), 1);
+    assert_eq!(count(TrickType::Flush), 1);
+    assert_eq!(tricks.len(), 2);
+}
+
+#[test]
+pub fn full_houses_and_four_of_a_kind_are_found(){
+    let hand = vec!(
+        card!(King, Clubs), card!(King, Hearts), card!(King, Spades), card!(King, Diamonds),
+        card!(Four, Hearts), card!(Four, Clubs));
+
+    let tricks: Vec<Move> = get_five_card_tricks(&hand).into_iter()
+        .map(|c| build_move(c).unwrap())
+        .collect();
+
+    // 4 ways to pick three kings, one pair of fours
+    let full_houses = tricks.iter().filter(|m| match **m {
+        Move::FiveCardTrick(t) => t.trick_type == TrickType::FullHouse,
+        _ => false
+    }).count();
+
+    let fours = tricks.len() - full_houses;
+
+    assert_eq!(full_houses, 4);
+    assert_eq!(fours, 2);
+}
+
+#[test]
+pub fn duplicate_cards_from_multiple_decks_give_one_move(){
+    let hand = vec!(card!(Five, Clubs), card!(Five, Clubs));
+
+    assert_eq!(get_singles(&hand).len(), 1);
+    assert_eq!(get_pairs(&hand).len(), 1);
+}
+
+#[test]
+pub fn choose_picks_every_subset_of_a_size(){
+    let cards = vec!(card!(Three, Clubs), card!(Four, Clubs), card!(Five, Clubs), card!(Six, Clubs));
+
+    assert_eq!(choose(&cards, 2).len(), 6);
+    assert_eq!(choose(&cards, 5).len(), 0);
+}
+
+#[test]
+pub fn jokers_can_complete_a_beating_pair(){
+    let pair_of_aces = build_move(vec!(card!(Ace, Clubs), card!(Ace, Hearts))).unwrap();
+
+    assert!(!can_beat(&[card!(King, Clubs), card!(Two, Hearts)], pair_of_aces));
+    assert!(can_beat(&[card!(King, Clubs), card!(Two, Hearts), PlayerCard::Joker(0)], pair_of_aces));
+}
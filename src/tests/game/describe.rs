@@ -0,0 +1,47 @@
+use cards::card::*;
+use cards::types::*;
+use cards::render::Style;
+use game::describe::*;
+use game::player_move::{Move, build_move};
+
+fn get_move(cards: Vec<PlayerCard>) -> Move {
+    build_move(cards).unwrap()
+}
+
+#[test]
+pub fn simple_moves_are_described(){
+    assert_eq!(describe_move(Move::Pass), "Pass");
+    assert_eq!(describe_move(get_move(vec!(card!(Nine, Spades)))), "Nine of Spades");
+    assert_eq!(describe_move(get_move(vec!(card!(Six, Clubs), card!(Six, Hearts)))), "Pair of 6s");
+    assert_eq!(describe_move(get_move(vec!(card!(Ace, Clubs), card!(Ace, Hearts), card!(Ace, Spades)))), "Prial of Aces");
+}
+
+#[test]
+pub fn a_full_house_is_described(){
+    let full_house = get_move(vec!(card!(Four, Clubs), card!(King, Clubs), card!(Four, Hearts),
+                                   card!(King, Hearts), card!(King, Spades)));
+
+    assert_eq!(describe_move(full_house), "Full house, Kings over 4s");
+}
+
+#[test]
+pub fn five_card_tricks_are_described(){
+    let straight = get_move(vec!(card!(Five, Clubs), card!(Six, Hearts), card!(Seven, Clubs),
+                                 card!(Eight, Diamonds), card!(Nine, Spades)));
+    let flush = get_move(vec!(card!(Three, Hearts), card!(Seven, Hearts), card!(Nine, Hearts),
+                              card!(Jack, Hearts), card!(Ace, Hearts)));
+    let four = get_move(vec!(card!(Nine, Clubs), card!(Nine, Hearts), card!(Nine, Diamonds),
+                             card!(Nine, Spades), card!(Three, Hearts)));
+
+    assert_eq!(describe_move(straight), "Straight, 9 high");
+    assert_eq!(describe_move(flush), "Flush, Ace high");
+    assert_eq!(describe_move(four), "Four of a kind, 9s");
+}
+
+#[test]
+pub fn move_cards_can_be_rendered(){
+    let pair = get_move(vec!(card!(Six, Clubs), card!(Six, Hearts)));
+
+    assert_eq!(render_move(pair, Style::Ascii), "6C 6H");
+    assert_eq!(render_move(Move::Pass, Style::Ascii), "");
+}
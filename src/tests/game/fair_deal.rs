@@ -0,0 +1,94 @@
+use game::fair_deal::FairDeal;
+use game::rules::{Rules, Variant};
+use cards::card::PlayerCard;
+
+fn get_deal() -> FairDeal {
+    FairDeal::new(vec!(0, 1, 2, 3), 1, 1234, "pepper")
+        .add_entropy(0, "first").unwrap()
+        .add_entropy(2, "third").unwrap()
+}
+
+#[test]
+pub fn a_revealed_deal_can_be_verified(){
+    let deal = get_deal();
+    let commitment = deal.get_commitment();
+
+    let (deal, game) = deal.deal(Rules::default()).unwrap();
+    let reveal = deal.reveal().unwrap();
+
+    assert!(reveal.verify_game(&commitment, &game).is_ok());
+    assert_eq!(reveal.verify(&commitment).unwrap().len(), 4);
+}
+
+#[test]
+pub fn a_tien_len_deal_can_be_verified(){
+    let deal = get_deal();
+    let commitment = deal.get_commitment();
+
+    let (deal, game) = deal.deal(Rules{ variant: Variant::TienLen, ..Rules::default() }).unwrap();
+    let reveal = deal.reveal().unwrap();
+    let hands = reveal.verify(&commitment).unwrap();
+
+    assert!(reveal.verify_game(&commitment, &game).is_ok());
+    assert!(hands.iter().all(|hand| hand.len() == 13));
+    assert!(!hands.iter().flatten().any(|c| matches!(*c, PlayerCard::Joker(_))));
+}
+
+#[test]
+pub fn the_commitment_does_not_depend_on_player_entropy(){
+    let plain = FairDeal::new(vec!(0, 1, 2, 3), 1, 1234, "pepper");
+
+    assert_eq!(plain.get_commitment(), get_deal().get_commitment());
+}
+
+#[test]
+pub fn player_entropy_changes_the_deal(){
+    let plain = FairDeal::new(vec!(0, 1, 2, 3), 1, 1234, "pepper");
+
+    let (_, a) = plain.deal(Rules::default()).unwrap();
+    let (_, b) = get_deal().deal(Rules::default()).unwrap();
+
+    assert!(a.players[0].get_hand() != b.players[0].get_hand());
+}
+
+#[test]
+pub fn a_changed_seed_does_not_match_the_commitment(){
+    let deal = get_deal();
+    let commitment = deal.get_commitment();
+    let (deal, _) = deal.deal(Rules::default()).unwrap();
+
+    let mut reveal = deal.reveal().unwrap();
+    reveal.seed += 1;
+
+    assert_eq!(reveal.verify(&commitment).err(), Some("The deal does not match the commitment"));
+}
+
+#[test]
+pub fn a_rigged_hand_is_found(){
+    let deal = get_deal();
+    let commitment = deal.get_commitment();
+    let (deal, mut game) = deal.deal(Rules::default()).unwrap();
+
+    let mut hand = game.players[0].get_hand();
+    let other = game.players[1].get_hand();
+    hand[0] = other[0];
+    game.players[0] = game.players[0].set_hand(hand);
+
+    let reveal = deal.reveal().unwrap();
+
+    assert_eq!(reveal.verify_game(&commitment, &game).err(), Some("The hands do not match the deal"));
+}
+
+#[test]
+pub fn entropy_cannot_be_added_after_the_deal(){
+    let (deal, _) = get_deal().deal(Rules::default()).unwrap();
+
+    assert!(deal.add_entropy(1, "late").is_err());
+    assert!(get_deal().add_entropy(0, "again").is_err());
+    assert!(get_deal().add_entropy(9, "stranger").is_err());
+}
+
+#[test]
+pub fn nothing_is_revealed_before_the_deal(){
+    assert!(get_deal().reveal().is_err());
+}
@@ -8,6 +8,7 @@ use cards::card::*;
 use cards::types::*;
 use game::player::Player;
 use game::round::Round;
+use game::rules::{Rules, UndoPolicy};
 use game::player_move::build_move;
 
 #[test]
@@ -41,12 +42,11 @@ pub fn game_can_load_in_any_state(){
     let player1 = Player::new(0).set_hand(vec!(card!(Ace, Spades)));
     let player2 = Player::new(1).set_hand(vec!(card!(Two, Hearts), card!(Two, Clubs)));
 
-    let game_definition = GameDefinition{
-        players: vec!(player1, player2),
-        round:Round::new(vec!(0, 1), 0, Move::Pass, 0, false),
-        winners: vec!(),
-        reversed: false
-    };
+    let game_definition = GameDefinition::new(
+        vec!(player1, player2),
+        Round::new(vec!(0, 1), 0, Move::Pass, 0, false),
+        vec!(),
+        false);
 
     let existing_game = Game::load(game_definition).unwrap();
 
@@ -93,12 +93,7 @@ pub fn once_the_game_has_started_three_clubs_is_just_another_card(){
 
     let round = Round::new(vec!(0, 1, 2), 1, single_three, 0, false);
     
-    let game_def = GameDefinition{
-        players: vec!(player1, player2, player3),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2, player3), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -120,12 +115,7 @@ pub fn valid_moves_return_new_game_definition(){
 
     let round = Round::new(vec!(0, 1), 0, single_three, 0, false);
     
-    let game_def = GameDefinition{
-        players: vec!(player1, player2),
-        round:round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -145,12 +135,7 @@ pub fn player_can_only_play_cards_in_its_hand(){
 
     let round = Round::new(vec!(0, 1), 0, single_three, 0, false); 
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -173,12 +158,7 @@ pub fn jokers_are_used_as_wildcards(){
 
     let round = Round::new(vec!(0, 1), 0, single_three, 0, false); 
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -199,12 +179,7 @@ pub fn player_loses_cards_when_move_is_valid(){
     let single_three = build_move(vec!(card!(Three, Clubs))).unwrap();
     let round = Round::new(vec!(0, 1), 0, single_three, 0, false); 
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -223,12 +198,7 @@ pub fn player_only_loses_cards_that_are_played(){
     let single_three = build_move(vec!(card!(Three, Clubs))).unwrap();
     let round = Round::new(vec!(0, 1), 0, single_three, 0, false); 
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -246,12 +216,7 @@ pub fn player_loses_all_cards_that_are_played(){
     let single_three = build_move(vec!(card!(Three, Clubs), card!(Three, Clubs))).unwrap();
     let round = Round::new(vec!(0, 1), 0, single_three, 0, false); 
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -270,12 +235,7 @@ pub fn player_loses_joker_when_playing_wildcard(){
 
     let round = Round::new(vec!(0, 1), 0, single_three, 0, false); 
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -294,12 +254,7 @@ pub fn player_using_last_card_wins(){
     let single_ten = build_move(vec!(card!(Ten, Clubs))).unwrap();
     let round = Round::new(vec!(0, 1), 0, single_ten, 0, false);
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -320,12 +275,11 @@ pub fn players_are_added_to_the_winners_vec_as_they_run_out_of_cards(){
 
     let round = Round::new(vec!(1, 2, 3), 2, single_ten, 0, false);
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2, player3, player4),
-        round: round,
-        winners: vec!(0),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(
+        vec!(player1, player2, player3, player4),
+        round,
+        vec!(0),
+        false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -348,12 +302,7 @@ pub fn winner_is_removed_from_play_rotation(){
     let single_ten = build_move(vec!(card!(Ten, Clubs))).unwrap();
     let round = Round::new(vec!(0, 1, 2), 0, single_ten, 0, false);
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2, player3),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2, player3), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -380,12 +329,11 @@ pub fn subsequent_finishing_players_are_removed(){
 
     let round = Round::new(vec!(1, 2, 3), 2, single_ten, 0, false);
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2, player3, player4),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(
+        vec!(player1, player2, player3, player4),
+        round,
+        vec!(),
+        false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -413,12 +361,11 @@ pub fn player_removal_is_reflected_in_the_stored_round(){
 
     let round = Round::new(vec!(1, 2, 3), 2, single_ten, 0, false);
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2, player3, player4),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(
+        vec!(player1, player2, player3, player4),
+        round,
+        vec!(),
+        false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -444,12 +391,7 @@ pub fn playing_a_four_card_trick_reverses_the_cards(){
 
     let round = Round::new(vec!(1, 2), 1, build_move(vec!()).unwrap(), 0, false);
     
-    let game_def = GameDefinition{
-        players: vec!(player1, player2),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -483,12 +425,7 @@ pub fn an_unbeatable_hand_auto_passes_other_players(){
 
     let round = Round::new(vec!(0, 1), 0, single_two, 0, false); 
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2),
-        round: round,
-        winners: vec!(),
-        reversed: true
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), true);
 
     let game = Game::load(game_def).unwrap();
 
@@ -508,12 +445,7 @@ pub fn when_a_player_exits_the_next_player_benefits_from_a_full_set_of_passes(){
 
     let round = Round::new(vec!(0, 1, 2), 1, single_two, 0, false); 
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2, player3),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2, player3), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -540,12 +472,7 @@ pub fn consecutive_reversals_will_cancel_each_other_out(){
 
     let round = Round::new(vec!(0, 1), 0, build_move(vec!()).unwrap(), 0, false);
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -571,12 +498,7 @@ pub fn immediately_following_a_reversal_with_an_invalid_reversal_bug(){
 
     let round = Round::new(vec!(0, 1), 0, build_move(vec!()).unwrap(), 0, false);
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -611,12 +533,7 @@ pub fn straights_are_compared_by_highest_card() {
 
     let round = Round::new(vec!(0, 1), 0, build_move(ten_high_straight).unwrap(), 0, false);
 
-    let game_def = GameDefinition{
-        players: vec!(player1, player2),
-        round: round,
-        winners: vec!(),
-        reversed: false
-    };
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), false);
 
     let game = Game::load(game_def).unwrap();
 
@@ -625,3 +542,280 @@ pub fn straights_are_compared_by_highest_card() {
 
     assert_eq!(game1_def.clone().round.get_next_player(), 1);
 }
+
+#[test]
+pub fn legal_moves_only_include_moves_that_beat_the_table(){
+    let player1 = Player::new(0).set_hand(vec!(card!(Four, Hearts), card!(Five, Clubs), card!(Five, Hearts), card!(Three, Spades)));
+    let player2 = Player::new(1).set_hand(vec!(card!(Three, Diamonds)));
+
+    let single_four = build_move(vec!(card!(Four, Clubs))).unwrap();
+    let round = Round::new(vec!(0, 1), 0, single_four, 0, false);
+
+    let game_def = GameDefinition::new(vec!(player1, player2), round, vec!(), false);
+
+    let game = Game::load(game_def).unwrap();
+    let moves = game.get_legal_moves(0);
+
+    assert_eq!(moves, vec!(
+        vec!(),
+        vec!(card!(Four, Hearts)),
+        vec!(card!(Five, Clubs)),
+        vec!(card!(Five, Hearts))));
+
+    assert_eq!(game.get_legal_moves(1), Vec::<Vec<PlayerCard>>::new());
+}
+
+fn get_undo_game(policy: UndoPolicy) -> Game {
+    let player1 = Player::new(0).set_hand(vec!(card!(Four, Hearts), card!(Five, Clubs), card!(Three, Clubs)));
+    let player2 = Player::new(1).set_hand(vec!(card!(Three, Diamonds), card!(Two, Spades)));
+    let player3 = Player::new(2).set_hand(vec!(card!(Six, Clubs), card!(Six, Hearts)));
+
+    let game_def = GameDefinition::new(
+        vec!(player1, player2, player3),
+        Round::new(vec!(0, 1, 2), 0, Move::Pass, 0, true),
+        vec!(),
+        false)
+        .with_rules(Rules{ undo: policy, ..Rules::default() });
+
+    Game::load(game_def).unwrap()
+}
+
+#[test]
+pub fn moves_cannot_be_undone_by_default(){
+    let game = get_undo_game(UndoPolicy::Disabled);
+    let game = Game::load(game.player_move(0, vec!(card!(Three, Clubs))).unwrap()).unwrap();
+
+    assert!(game.undo(0, 1).is_err());
+}
+
+#[test]
+pub fn a_player_can_take_back_their_move_before_the_next_player_acts(){
+    let game = get_undo_game(UndoPolicy::BeforeNextAction);
+    let before = game.export();
+
+    let game = Game::load(game.player_move(0, vec!(card!(Three, Clubs))).unwrap()).unwrap();
+
+    // only the player who moved can take it back
+    assert!(game.undo(1, 1).is_err());
+
+    let undone = game.undo(0, 1).unwrap();
+
+    assert_eq!(undone.players, before.players);
+    assert_eq!(undone.round, before.round);
+    assert_eq!(undone.history.len(), 0);
+}
+
+#[test]
+pub fn a_move_cannot_be_taken_back_once_the_next_player_has_acted(){
+    let game = get_undo_game(UndoPolicy::BeforeNextAction);
+    let game = Game::load(game.player_move(0, vec!(card!(Three, Clubs))).unwrap()).unwrap();
+    let game = Game::load(game.player_move(1, vec!(card!(Three, Diamonds))).unwrap()).unwrap();
+
+    assert!(game.undo(0, 1).is_err());
+    assert!(game.undo(1, 2).is_err());
+    assert!(game.undo(1, 1).is_ok());
+}
+
+#[test]
+pub fn practice_games_can_undo_several_moves(){
+    let game = get_undo_game(UndoPolicy::Unrestricted);
+    let before = game.export();
+
+    let game = Game::load(game.player_move(0, vec!(card!(Three, Clubs))).unwrap()).unwrap();
+    let game = Game::load(game.player_move(1, vec!()).unwrap()).unwrap();
+    let after_pass = game.export();
+    let game = Game::load(game.player_move(2, vec!(card!(Six, Clubs))).unwrap()).unwrap();
+
+    let undone = game.undo(0, 1).unwrap();
+    assert_eq!(undone.round, after_pass.round);
+    assert_eq!(undone.round.export().pass_count, 1);
+
+    let undone = game.undo(2, 3).unwrap();
+    assert_eq!(undone.players, before.players);
+    assert_eq!(undone.round, before.round);
+
+    assert!(game.undo(0, 4).is_err());
+}
+
+#[test]
+pub fn undoing_restores_winners_and_reversal(){
+    let player1 = Player::new(0).set_hand(vec!(
+            card!(Four, Hearts), card!(Four, Clubs), card!(Four, Spades), card!(Four, Diamonds), card!(Five, Clubs)));
+    let player2 = Player::new(1).set_hand(vec!(card!(Three, Diamonds)));
+    let player3 = Player::new(2).set_hand(vec!(card!(Six, Clubs)));
+
+    let game_def = GameDefinition::new(
+        vec!(player1, player2, player3),
+        Round::new(vec!(0, 1, 2), 0, Move::Pass, 0, false),
+        vec!(),
+        false)
+        .with_rules(Rules{ undo: UndoPolicy::Unrestricted, ..Rules::default() });
+
+    let game = Game::load(game_def.clone()).unwrap();
+    let played = game.player_move(0, vec!(
+            card!(Four, Hearts), card!(Four, Clubs), card!(Four, Spades), card!(Four, Diamonds), card!(Five, Clubs))).unwrap();
+
+    assert!(played.reversed);
+    assert_eq!(played.winners, vec!(0));
+
+    let undone = Game::load(played).unwrap().undo(0, 1).unwrap();
+
+    assert!(!undone.reversed);
+    assert_eq!(undone.winners, Vec::<u64>::new());
+    assert_eq!(undone.players, game_def.players);
+    assert_eq!(undone.round, game_def.round);
+}
+
+fn get_last_card_game(rules: Rules) -> Game {
+    let hands = vec!(
+        vec!(card!(Three, Clubs), card!(Five, Clubs), card!(King, Hearts), card!(Ace, Spades)),
+        vec!(card!(Four, Clubs), card!(Six, Clubs)),
+        vec!(card!(Seven, Clubs), card!(Eight, Clubs), card!(Nine, Clubs)));
+
+    let game_def = Game::setup_with_hands(vec!(0, 1, 2), hands, rules).unwrap();
+    let game = Game::load(game_def).unwrap();
+    let game = Game::load(game.player_move(0, vec!(card!(Three, Clubs))).unwrap()).unwrap();
+    let game = Game::load(game.player_move(1, vec!(card!(Four, Clubs))).unwrap()).unwrap();
+    Game::load(game.player_move(2, vec!()).unwrap()).unwrap()
+}
+
+#[test]
+pub fn a_low_single_can_be_played_against_a_last_card_by_default(){
+    let game = get_last_card_game(Rules::default());
+
+    assert!(game.player_move(0, vec!(card!(Five, Clubs))).is_ok());
+}
+
+#[test]
+pub fn the_highest_single_must_be_played_against_a_last_card(){
+    let rules = Rules{ forced_highest_single: true, ..Rules::default() };
+    let game = get_last_card_game(rules);
+
+    assert_eq!(game.player_move(0, vec!(card!(King, Hearts))).err(),
+               Some("You must play your highest single when the next player has one card"));
+    assert!(game.player_move(0, vec!(card!(Ace, Spades))).is_ok());
+    assert!(game.player_move(0, vec!()).is_ok());
+}
+
+#[test]
+pub fn any_single_can_be_played_when_the_next_player_has_more_cards(){
+    let rules = Rules{ forced_highest_single: true, ..Rules::default() };
+    let hands = vec!(
+        vec!(card!(Three, Clubs), card!(Five, Clubs), card!(King, Hearts)),
+        vec!(card!(Four, Clubs), card!(Six, Clubs)));
+    let game = Game::load(Game::setup_with_hands(vec!(0, 1), hands, rules).unwrap()).unwrap();
+
+    assert!(game.player_move(0, vec!(card!(Three, Clubs))).is_ok());
+}
+
+#[test]
+pub fn the_last_card_must_be_announced(){
+    let rules = Rules{ announce_last_card: true, ..Rules::default() };
+    let hands = vec!(
+        vec!(card!(Three, Clubs), card!(Five, Clubs), card!(King, Hearts)),
+        vec!(card!(Four, Clubs), card!(Six, Clubs)));
+    let game = Game::load(Game::setup_with_hands(vec!(0, 1), hands, rules).unwrap()).unwrap();
+
+    assert_eq!(game.player_move_announcing(0, vec!(card!(Three, Clubs)), true).err(),
+               Some("You can only announce your last card when playing down to one card"));
+
+    let game = Game::load(game.player_move(0, vec!(card!(Three, Clubs))).unwrap()).unwrap();
+
+    assert_eq!(game.player_move(1, vec!(card!(Four, Clubs))).err(), Some("You must announce your last card"));
+    assert!(game.player_move_announcing(1, vec!(card!(Four, Clubs)), true).is_ok());
+}
+
+#[test]
+pub fn a_game_definition_starts_with_no_history_and_default_rules(){
+    let player1 = Player::new(0).set_hand(vec!(card!(Three, Clubs)));
+    let player2 = Player::new(1).set_hand(vec!(card!(Four, Clubs)));
+
+    let game_def = GameDefinition::new(vec!(player1, player2), Round::new(vec!(0, 1), 0, Move::Pass, 0, true), vec!(), false);
+
+    assert!(game_def.history.is_empty());
+    assert_eq!(game_def.rules, Rules::default());
+    assert_eq!(game_def.setup, Default::default());
+
+    let rules = Rules{ undo: UndoPolicy::Unrestricted, ..Rules::default() };
+    assert_eq!(game_def.with_rules(rules.clone()).rules, rules);
+}
+
+#[test]
+pub fn legal_moves_and_hints_keep_to_the_highest_single_rule(){
+    let rules = Rules{ forced_highest_single: true, ..Rules::default() };
+    let game = get_last_card_game(rules);
+
+    let moves = game.get_legal_moves(0);
+    assert!(moves.contains(&vec!(card!(Ace, Spades))));
+    assert!(!moves.contains(&vec!(card!(King, Hearts))));
+    assert!(!moves.contains(&vec!(card!(Five, Clubs))));
+
+    assert!(game.get_hints().iter().all(|hint| hint.cards != vec!(card!(King, Hearts))));
+}
+
+#[test]
+pub fn a_move_out_of_turn_is_refused_before_the_last_card_rules(){
+    let rules = Rules{ announce_last_card: true, ..Rules::default() };
+    let hands = vec!(
+        vec!(card!(Three, Clubs), card!(Five, Clubs), card!(King, Hearts)),
+        vec!(card!(Four, Clubs), card!(Six, Clubs)));
+    let game = Game::load(Game::setup_with_hands(vec!(0, 1), hands, rules).unwrap()).unwrap();
+
+    assert_eq!(game.player_move(1, vec!(card!(Four, Clubs))).err(), Some("move was invalid"));
+}
+
+#[test]
+pub fn a_player_with_one_card_can_pass_without_announcing(){
+    let rules = Rules{ announce_last_card: true, ..Rules::default() };
+    let hands = vec!(
+        vec!(card!(Three, Clubs), card!(Five, Clubs), card!(King, Hearts)),
+        vec!(card!(Four, Clubs), card!(Six, Clubs)));
+    let game = Game::load(Game::setup_with_hands(vec!(0, 1), hands, rules).unwrap()).unwrap();
+    let game = Game::load(game.player_move(0, vec!(card!(Three, Clubs))).unwrap()).unwrap();
+    let game = Game::load(game.player_move_announcing(1, vec!(card!(Four, Clubs)), true).unwrap()).unwrap();
+    let game = Game::load(game.player_move_announcing(0, vec!(card!(King, Hearts)), true).unwrap()).unwrap();
+
+    assert!(game.get_legal_moves(1).contains(&vec!()));
+    assert!(game.player_move(1, vec!()).is_ok());
+    assert_eq!(game.player_move_announcing(1, vec!(), true).err(),
+               Some("You can only announce your last card when playing down to one card"));
+}
+
+#[test]
+pub fn an_announcement_is_kept_in_the_history(){
+    let rules = Rules{ announce_last_card: true, ..Rules::default() };
+    let hands = vec!(
+        vec!(card!(Three, Clubs), card!(Five, Clubs)),
+        vec!(card!(Four, Clubs), card!(Six, Clubs), card!(Seven, Clubs)));
+    let game = Game::load(Game::setup_with_hands(vec!(0, 1), hands, rules).unwrap()).unwrap();
+
+    let game_def = game.player_move_announcing(0, vec!(card!(Three, Clubs)), true).unwrap();
+
+    assert!(game_def.history[0].last_card);
+}
+
+#[test]
+pub fn a_bare_joker_is_not_a_move(){
+    let hands = vec!(
+        vec!(card!(Three, Clubs), PlayerCard::Joker(1)),
+        vec!(card!(Four, Clubs), card!(Six, Clubs)));
+    let game = Game::load(Game::setup_with_hands(vec!(0, 1), hands, Rules::default()).unwrap()).unwrap();
+
+    assert_eq!(game.player_move(0, vec!(PlayerCard::Joker(1))).err(), Some("Invalid move!"));
+    assert_eq!(game.player_move(0, vec!(card!(Three, Clubs), PlayerCard::Joker(1))).err(), Some("Invalid move!"));
+}
+
+#[test]
+pub fn wildcards_need_a_joker_in_the_hand(){
+    let hands = vec!(
+        vec!(card!(Three, Clubs), card!(Five, Clubs), PlayerCard::Joker(1)),
+        vec!(card!(Four, Clubs), card!(Six, Clubs)));
+    let game = Game::load(Game::setup_with_hands(vec!(0, 1), hands, Rules::default()).unwrap()).unwrap();
+
+    assert_eq!(game.player_move(0, vec!(card!(Three, Clubs), wildcard!(Three, Hearts), wildcard!(Three, Spades))).err(),
+               Some("Cannot play cards you do not have"));
+    assert!(game.player_move(0, vec!(card!(Three, Clubs), wildcard!(Three, Hearts))).is_ok());
+
+    let game = Game::load(game.player_move(0, vec!(card!(Three, Clubs))).unwrap()).unwrap();
+    assert_eq!(game.player_move(1, vec!(wildcard!(Ace, Spades))).err(), Some("Cannot play cards you do not have"));
+}
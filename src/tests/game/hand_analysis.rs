@@ -0,0 +1,61 @@
+use cards::card::*;
+use cards::types::*;
+use game::hand_analysis::*;
+use game::player::Player;
+use game::player_move::{Move, TrickType};
+
+#[test]
+pub fn matching_ranks_are_grouped_together(){
+    let player = Player::new(0).set_hand(vec!(
+        card!(Four, Hearts), card!(Four, Clubs), card!(Nine, Clubs),
+        card!(Nine, Hearts), card!(Nine, Spades), card!(King, Diamonds)));
+
+    let partitions = analyse_hand(&player, 5);
+
+    // full house and a single, or pair, prial and single
+    assert_eq!(partitions.len(), 2);
+    assert_eq!(partitions[0].plays.len(), 2);
+    assert_eq!(partitions[1].plays.len(), 3);
+}
+
+#[test]
+pub fn five_card_tricks_reduce_the_number_of_plays(){
+    let player = Player::new(0).set_hand(vec!(
+        card!(Three, Clubs), card!(Four, Hearts), card!(Five, Clubs),
+        card!(Six, Spades), card!(Seven, Diamonds), card!(Jack, Diamonds)));
+
+    let best = &analyse_hand(&player, 5)[0];
+
+    assert_eq!(best.plays.len(), 2);
+    assert!(best.get_moves().iter().any(|m| match *m {
+        Move::FiveCardTrick(t) => t.trick_type == TrickType::Straight,
+        _ => false
+    }));
+}
+
+#[test]
+pub fn control_plays_break_ties(){
+    let player = Player::new(0).set_hand(vec!(
+        card!(Two, Clubs), card!(Two, Hearts), card!(Five, Clubs), PlayerCard::Joker(0)));
+
+    let best = &analyse_hand(&player, 5)[0];
+
+    // a pair of twos and a joker
+    assert_eq!(best.control_plays, 2);
+    assert_eq!(best.plays.len(), 2);
+}
+
+#[test]
+pub fn partitions_are_ordered_best_first(){
+    let player = Player::new(0).set_hand(vec!(
+        card!(Three, Hearts), card!(Five, Hearts), card!(Seven, Hearts),
+        card!(Nine, Hearts), card!(Jack, Hearts), card!(Jack, Clubs),
+        card!(King, Spades)));
+
+    let partitions = analyse_hand(&player, 10);
+
+    assert!(partitions.len() > 1);
+    assert!(partitions.windows(2).all(|w| w[0].score >= w[1].score));
+    assert_eq!(partitions[0].plays.len(), 3);
+    assert_eq!(analyse_hand(&player, 1).len(), 1);
+}
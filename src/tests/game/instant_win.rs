@@ -0,0 +1,115 @@
+use game::game::Game;
+use game::instant_win::*;
+use game::rules::Rules;
+use cards::card::*;
+use cards::types::*;
+
+const ALL: [InstantWin; 4] = [InstantWin::Dragon, InstantWin::FourTwos, InstantWin::FivePairsAndPrial, InstantWin::SixPairs];
+
+fn get_dragon() -> Vec<PlayerCard> {
+    vec!(card!(Three, Clubs), card!(Four, Hearts), card!(Five, Clubs), card!(Six, Spades),
+         card!(Seven, Clubs), card!(Eight, Diamonds), card!(Nine, Clubs), card!(Ten, Clubs),
+         card!(Jack, Hearts), card!(Queen, Clubs), card!(King, Clubs), card!(Ace, Spades), card!(Two, Clubs))
+}
+
+fn get_pairs() -> Vec<PlayerCard> {
+    vec!(card!(Four, Clubs), card!(Four, Hearts), card!(Five, Clubs), card!(Five, Spades),
+         card!(Seven, Clubs), card!(Seven, Diamonds), card!(Nine, Clubs), card!(Nine, Hearts),
+         card!(Jack, Hearts), card!(Jack, Clubs), card!(King, Clubs), card!(King, Spades), card!(Two, Diamonds))
+}
+
+#[test]
+pub fn a_dragon_is_found(){
+    assert_eq!(find_instant_win(&get_dragon(), &ALL), Some(InstantWin::Dragon));
+}
+
+#[test]
+pub fn only_allowed_patterns_are_found(){
+    assert_eq!(find_instant_win(&get_dragon(), &[InstantWin::SixPairs]), None);
+    assert_eq!(find_instant_win(&get_dragon(), &[]), None);
+}
+
+#[test]
+pub fn four_twos_are_found(){
+    let mut hand = get_pairs();
+    hand.truncate(9);
+    hand.extend(vec!(card!(Two, Clubs), card!(Two, Hearts), card!(Two, Diamonds), card!(Two, Spades)));
+
+    assert_eq!(find_instant_win(&hand, &[InstantWin::FourTwos]), Some(InstantWin::FourTwos));
+}
+
+#[test]
+pub fn six_pairs_are_found(){
+    assert_eq!(find_instant_win(&get_pairs(), &ALL), Some(InstantWin::SixPairs));
+}
+
+#[test]
+pub fn five_pairs_and_a_prial_are_found(){
+    let mut hand = get_pairs();
+    hand[12] = card!(Four, Diamonds);
+
+    assert_eq!(find_instant_win(&hand, &ALL), Some(InstantWin::FivePairsAndPrial));
+}
+
+#[test]
+pub fn ordinary_hands_do_not_win(){
+    let mut hand = get_pairs();
+    hand[1] = card!(Three, Clubs);
+    hand[11] = card!(Eight, Hearts);
+
+    assert_eq!(find_instant_win(&hand, &ALL), None);
+}
+
+fn get_hands() -> Vec<Vec<PlayerCard>> {
+    vec!(
+        vec!(card!(Three, Diamonds), card!(Six, Hearts)),
+        get_pairs(),
+        get_dragon()
+    )
+}
+
+#[test]
+pub fn a_game_ends_when_an_instant_win_is_dealt(){
+    let rules = Rules{ instant_wins: ALL.to_vec(), ..Rules::default() };
+
+    let game_def = Game::setup_with_hands(vec!(0, 1, 2), get_hands(), rules).unwrap();
+
+    assert_eq!(game_def.setup.instant_wins, vec!(
+        InstantWinClaim{ player: 2, hand: InstantWin::Dragon },
+        InstantWinClaim{ player: 1, hand: InstantWin::SixPairs }
+    ));
+    assert_eq!(game_def.winners, vec!(2, 1));
+
+    let game = Game::load(game_def.clone()).unwrap();
+    assert!(game.is_finished());
+    assert_eq!(game.get_outcome().unwrap().finishing_order, vec!(2, 1, 0));
+    assert_eq!(game.player_move(2, vec!(card!(Three, Clubs))).err(), Some("The game was won on the deal"));
+    assert!(!game_def.validate().iter().any(|v| matches!(*v, ::game::validator::Violation::WinnerHasCards(_))));
+}
+
+#[test]
+pub fn instant_wins_are_off_by_default(){
+    let game_def = Game::setup_with_hands(vec!(0, 1, 2), get_hands(), Rules::default()).unwrap();
+
+    assert!(game_def.setup.instant_wins.is_empty());
+    assert!(!Game::load(game_def).unwrap().is_finished());
+}
+
+#[test]
+pub fn only_thirteen_card_hands_can_win(){
+    let mut hand = get_pairs();
+    hand.extend(get_dragon());
+
+    assert_eq!(find_instant_win(&hand, &ALL), None);
+    assert_eq!(find_instant_win(&get_pairs()[..12], &ALL), None);
+}
+
+#[test]
+pub fn a_two_player_deal_does_not_win_on_the_deal(){
+    let rules = Rules{ instant_wins: ALL.to_vec(), ..Rules::default() };
+
+    for seed in 0..20 {
+        let game_def = Game::setup_seeded(vec!(0, 1), 1, rules.clone(), seed).unwrap();
+        assert!(game_def.setup.instant_wins.is_empty());
+    }
+}
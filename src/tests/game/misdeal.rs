@@ -0,0 +1,124 @@
+use game::game::Game;
+use game::misdeal::*;
+use game::rules::Rules;
+use cards::card::*;
+use cards::types::*;
+
+fn get_weak_hand() -> Vec<PlayerCard> {
+    vec!(card!(Three, Clubs), card!(Four, Hearts), card!(Five, Clubs), card!(Six, Spades),
+         card!(Seven, Clubs), card!(Eight, Diamonds), card!(Nine, Clubs), card!(Ten, Clubs),
+         card!(Three, Hearts), card!(Four, Clubs), card!(Five, Spades), card!(Six, Hearts), card!(Nine, Diamonds))
+}
+
+fn get_rules() -> Rules {
+    Rules{ misdeals: vec!(Misdeal::NoCardAbove(Rank::Two)), ..Rules::default() }
+}
+
+#[test]
+pub fn a_hand_with_no_card_above_ten_is_a_misdeal(){
+    let misdeals = [Misdeal::NoCardAbove(Rank::Ten)];
+    let mut hand = get_weak_hand();
+
+    assert!(is_misdeal(&hand, &misdeals));
+
+    hand[0] = card!(Jack, Clubs);
+    assert!(!is_misdeal(&hand, &misdeals));
+}
+
+#[test]
+pub fn a_hand_with_no_face_cards_is_a_misdeal(){
+    let misdeals = [Misdeal::NoFaceCards];
+    let mut hand = get_weak_hand();
+    hand[0] = card!(Ace, Clubs);
+    hand[1] = card!(Two, Spades);
+
+    assert!(is_misdeal(&hand, &misdeals));
+
+    hand[2] = card!(Queen, Hearts);
+    assert!(!is_misdeal(&hand, &misdeals));
+}
+
+#[test]
+pub fn a_hand_with_a_joker_is_not_a_misdeal(){
+    let mut hand = get_weak_hand();
+    hand[0] = PlayerCard::Joker(1);
+
+    assert!(!is_misdeal(&hand, &[Misdeal::NoCardAbove(Rank::Ten), Misdeal::NoFaceCards]));
+}
+
+#[test]
+pub fn nothing_is_a_misdeal_by_default(){
+    assert!(!is_misdeal(&get_weak_hand(), &[]));
+
+    let game = Game::setup_seeded(vec!(1, 2, 3, 4), 1, Rules::default(), 7).unwrap();
+    assert!(game.setup.misdeals.is_empty());
+}
+
+#[test]
+pub fn misdealt_players_are_recorded(){
+    let hands = vec!(get_weak_hand(), vec!(card!(Two, Spades)));
+    let rules = Rules{ misdeals: vec!(Misdeal::NoCardAbove(Rank::Ten)), ..Rules::default() };
+    let game = Game::setup_with_hands(vec!(1, 2), hands, rules).unwrap();
+
+    assert_eq!(game.setup.misdeals, vec!(1));
+}
+
+#[test]
+pub fn a_misdealt_player_can_ask_for_a_redeal(){
+    let game_def = Game::setup_seeded(vec!(1, 2, 3, 4), 1, get_rules(), 7).unwrap();
+    let player = game_def.setup.misdeals[0];
+    let game = Game::load(game_def.clone()).unwrap();
+
+    let redealt = game.request_redeal(player).unwrap();
+
+    assert_eq!(redealt.setup.redeals, 1);
+    assert_eq!(redealt.setup.seed, Some(7));
+    assert_eq!(redealt.setup.num_decks, 1);
+    assert!(redealt.players != game_def.players);
+}
+
+#[test]
+pub fn seeded_redeals_are_reproducible(){
+    let first = Game::load(Game::setup_seeded(vec!(1, 2, 3, 4), 1, get_rules(), 7).unwrap()).unwrap();
+    let second = Game::load(Game::setup_seeded(vec!(1, 2, 3, 4), 1, get_rules(), 7).unwrap()).unwrap();
+    let player = first.export().setup.misdeals[0];
+
+    let first = Game::load(first.request_redeal(player).unwrap()).unwrap();
+    let second = Game::load(second.request_redeal(player).unwrap()).unwrap();
+    assert_eq!(first.export().players, second.export().players);
+
+    let player = first.export().setup.misdeals[0];
+    let again = first.request_redeal(player).unwrap();
+    assert_eq!(again.setup.redeals, 2);
+    assert_eq!(again.players, second.request_redeal(player).unwrap().players);
+    assert!(again.players != first.export().players);
+}
+
+#[test]
+pub fn only_a_misdealt_player_can_ask_for_a_redeal(){
+    let hands = vec!(get_weak_hand(), vec!(card!(Two, Spades)));
+    let rules = Rules{ misdeals: vec!(Misdeal::NoCardAbove(Rank::Ten)), ..Rules::default() };
+    let game = Game::load(Game::setup_with_hands(vec!(1, 2), hands, rules).unwrap()).unwrap();
+
+    assert_eq!(game.request_redeal(2).err(), Some("Only a player with a misdeal can ask for a redeal"));
+}
+
+#[test]
+pub fn hands_not_dealt_from_fresh_decks_cannot_be_redealt(){
+    let hands = vec!(get_weak_hand(), vec!(card!(Two, Spades)));
+    let rules = Rules{ misdeals: vec!(Misdeal::NoCardAbove(Rank::Ten)), ..Rules::default() };
+    let game = Game::load(Game::setup_with_hands(vec!(1, 2), hands, rules).unwrap()).unwrap();
+
+    assert_eq!(game.request_redeal(1).err(), Some("This game was not dealt from fresh decks"));
+}
+
+#[test]
+pub fn there_is_no_redeal_once_play_has_started(){
+    let game_def = Game::setup_seeded(vec!(1, 2, 3, 4), 1, get_rules(), 7).unwrap();
+    let game = Game::load(game_def).unwrap();
+    let first = game.get_next_player().unwrap().get_id();
+    let game = Game::load(game.player_move(first, vec!(card!(Three, Clubs))).unwrap()).unwrap();
+    let player = game.export().setup.misdeals[0];
+
+    assert_eq!(game.request_redeal(player).err(), Some("The game has already started"));
+}
@@ -2,3 +2,23 @@ pub mod player;
 pub mod player_move;
 pub mod round;
 pub mod game;
+pub mod store;
+pub mod combinations;
+pub mod advisor;
+pub mod hand_analysis;
+pub mod tracker;
+pub mod spectator;
+pub mod probability;
+pub mod validator;
+#[cfg(feature = "signing")]
+pub mod signing;
+#[cfg(feature = "signing")]
+pub mod fair_deal;
+pub mod runner;
+pub mod describe;
+pub mod notation;
+pub mod tien_len;
+pub mod instant_win;
+pub mod misdeal;
+pub mod teams;
+pub mod tribute;
@@ -0,0 +1,211 @@
+use game::game::{ Game, GameDefinition, Outcome };
+use game::notation::GameRecord;
+use game::rules::{ Rules, UndoPolicy, Variant };
+use game::instant_win::InstantWin;
+use game::misdeal::Misdeal;
+use game::teams::Team;
+use game::tribute::Exchange;
+use game::player_move::Move;
+use cards::card::*;
+use cards::types::*;
+
+// play the first legal move every turn until the game is over
+fn play_out(mut game_def: GameDefinition) -> GameDefinition {
+    for _ in 0..500 {
+        let game = Game::load(game_def.clone()).unwrap();
+        if game.is_finished() {
+            break;
+        }
+
+        let player = game.get_next_player().unwrap().get_id();
+        let moves = game.get_legal_moves(player);
+        let cards = match moves.iter().find(|m| !m.is_empty()) {
+            Some(m) => m.clone(),
+            // jokers are not offered as moves, so lead with one by hand
+            None if game.get_last_move() == Move::Pass => vec!(wildcard!(Two, Spades, game.is_reversed())),
+            None => vec!()
+        };
+        game_def = game.player_move(player, cards).unwrap();
+    }
+
+    game_def
+}
+
+fn get_record() -> (GameDefinition, GameRecord) {
+    let game_def = play_out(Game::setup_seeded(vec!(1, 2, 3, 4), 1, Rules::default(), 42).unwrap());
+    let record = GameRecord::new(&game_def).unwrap().with_seed(42).with_date("2024.06.01");
+
+    (game_def, record)
+}
+
+#[test]
+pub fn a_game_survives_a_round_trip(){
+    let (game_def, record) = get_record();
+    let text = record.to_string();
+
+    let parsed = GameRecord::parse(&text).unwrap();
+
+    assert_eq!(parsed.to_string(), text);
+    assert_eq!(parsed.replay().unwrap().winners, game_def.winners);
+    assert_eq!(parsed.result, Some(Game::load(game_def).unwrap().get_outcome().unwrap().finishing_order));
+}
+
+#[test]
+pub fn a_record_starts_with_its_tags(){
+    let (_, record) = get_record();
+    let text = record.to_string();
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert_eq!(lines[0], "[Players \"1 2 3 4\"]");
+    assert_eq!(lines[1], "[Seed \"42\"]");
+    assert_eq!(lines[2], "[Date \"2024.06.01\"]");
+    assert!(lines[3].starts_with("[Result \""));
+    assert!(text.contains("1. "));
+}
+
+fn get_short_game() -> String {
+    "[Players \"1 2\"]\n\
+     [Result \"*\"]\n\
+     \n\
+     1: 3C 4C 5C\n\
+     2: 6C 7C JK\n\
+     \n\
+     1. 1 3C\n\
+     2. 2 6C\n\
+     3. 1 pass\n".to_string()
+}
+
+#[test]
+pub fn a_game_in_progress_can_be_read(){
+    let record = GameRecord::parse(&get_short_game()).unwrap();
+
+    assert_eq!(record.result, None);
+    assert_eq!(record.deal[1], vec!(card!(Six, Clubs), card!(Seven, Clubs), PlayerCard::Joker(0)));
+    assert_eq!(record.turns.len(), 3);
+    assert!(record.turns[2].is_pass());
+}
+
+#[test]
+pub fn illegal_moves_are_refused(){
+    let text = get_short_game().replace("2. 2 6C", "2. 2 4C");
+
+    assert_eq!(GameRecord::parse(&text).err(), Some("Cannot play cards you do not have"));
+}
+
+#[test]
+pub fn a_wrong_result_is_refused(){
+    let text = get_short_game().replace("[Result \"*\"]", "[Result \"2 1\"]");
+
+    assert_eq!(GameRecord::parse(&text).err(), Some("The result does not match the moves"));
+}
+
+#[test]
+pub fn a_deal_that_does_not_match_the_seed_is_refused(){
+    let (_, record) = get_record();
+    let text = record.to_string().replace("[Seed \"42\"]", "[Seed \"43\"]");
+
+    assert_eq!(GameRecord::parse(&text).err(), Some("The deal does not match the seed"));
+}
+
+#[test]
+pub fn garbage_is_refused(){
+    assert!(GameRecord::parse("hello").is_err());
+    assert!(GameRecord::parse("[Players \"1 x\"]").is_err());
+}
+
+#[test]
+pub fn a_last_card_announcement_survives_a_round_trip(){
+    let rules = Rules{ announce_last_card: true, ..Rules::default() };
+    let hands = vec!(
+        vec!(card!(Three, Clubs), card!(Five, Clubs)),
+        vec!(card!(Four, Clubs), card!(Six, Clubs), card!(Seven, Clubs)));
+    let game = Game::load(Game::setup_with_hands(vec!(1, 2), hands, rules).unwrap()).unwrap();
+    let game_def = game.player_move_announcing(1, vec!(card!(Three, Clubs)), true).unwrap();
+
+    let text = GameRecord::new(&game_def).unwrap().to_string();
+    assert!(text.contains("1. 1 3C last"));
+
+    let parsed = GameRecord::parse(&text).unwrap();
+    assert!(parsed.turns[0].last_card);
+    assert!(GameRecord::parse(&text.replace("3C last", "3C")).is_err());
+}
+
+#[test]
+pub fn rules_are_written_as_tags(){
+    let rules = Rules{
+        undo: UndoPolicy::BeforeNextAction,
+        instant_wins: vec!(InstantWin::Dragon, InstantWin::SixPairs),
+        misdeals: vec!(Misdeal::NoFaceCards, Misdeal::NoCardAbove(Rank::Nine)),
+        teams: vec!(Team{ players: vec!(1, 3) }, Team{ players: vec!(2, 4) }),
+        partner_takes_lead: true,
+        announce_last_card: true,
+        ..Rules::default()
+    };
+    let game_def = Game::setup_seeded(vec!(1, 2, 3, 4), 1, rules.clone(), 42).unwrap();
+    let text = GameRecord::new(&game_def).unwrap().to_string();
+
+    assert!(text.contains("[Undo \"BeforeNextAction\"]"));
+    assert!(text.contains("[InstantWins \"Dragon, SixPairs\"]"));
+    assert!(text.contains("[Misdeals \"NoFaceCards, NoCardAbove 9\"]"));
+    assert!(text.contains("[Teams \"1 3, 2 4\"]"));
+    assert!(text.contains("[PartnerTakesLead \"Yes\"]"));
+    assert!(text.contains("[AnnounceLastCard \"Yes\"]"));
+    assert!(!text.contains("Variant"));
+    assert!(!text.contains("Tribute"));
+    assert_eq!(GameRecord::parse(&text).unwrap().rules, rules);
+}
+
+#[test]
+pub fn unknown_rule_values_are_refused(){
+    let text = get_short_game().replace("[Result", "[Variant \"Bridge\"]\n[Result");
+
+    assert_eq!(GameRecord::parse(&text).err(), Some("Unknown variant"));
+}
+
+#[test]
+pub fn a_redealt_game_matches_its_seed(){
+    let rules = Rules{ variant: Variant::TienLen, misdeals: vec!(Misdeal::NoCardAbove(Rank::Two)), ..Rules::default() };
+    let game_def = Game::setup_seeded(vec!(1, 2, 3, 4), 1, rules, 42).unwrap();
+    let redealt = Game::load(game_def.clone()).unwrap().request_redeal(game_def.setup.misdeals[0]).unwrap();
+
+    let text = GameRecord::new(&redealt).unwrap().with_seed(42).to_string();
+
+    assert!(text.contains("[Variant \"TienLen\"]"));
+    assert!(text.contains("[Redeals \"1\"]"));
+    assert_eq!(GameRecord::parse(&text).unwrap().redeals, 1);
+    assert_eq!(GameRecord::parse(&text.replace("[Redeals \"1\"]\n", "")).err(), Some("The deal does not match the seed"));
+}
+
+#[test]
+pub fn tributes_are_recorded_between_the_deal_and_the_moves(){
+    let rules = Rules{ tribute: vec!(1), ..Rules::default() };
+    let dealt = Game::setup_seeded(vec!(1, 2, 3, 4), 1, rules, 42).unwrap();
+    let exchange = Exchange::new(dealt.clone(), &Outcome{ finishing_order: vec!(2, 3, 1, 4) }).unwrap();
+
+    let given = exchange.get_tributes()[0].given.clone();
+    let winner_hand = exchange.get_game().players[1].get_hand();
+    let returned = winner_hand.iter().find(|c| !given.contains(c)).cloned().unwrap();
+    let game_def = exchange.return_cards(2, vec!(returned)).unwrap().start().unwrap();
+
+    let record = GameRecord::new(&game_def).unwrap().with_seed(42);
+    let text = record.to_string();
+    assert!(text.contains("\n4 > 2: "));
+
+    let parsed = GameRecord::parse(&text).unwrap();
+    assert_eq!(parsed.tributes, record.tributes);
+    assert_eq!(parsed.deal, GameRecord::new(&dealt).unwrap().deal);
+
+    let replayed = parsed.replay().unwrap();
+    for (player, original) in replayed.players.iter().zip(game_def.players.iter()) {
+        assert_eq!(player.remaining_cards(), original.remaining_cards());
+    }
+}
+
+#[test]
+pub fn a_seeded_game_keeps_its_seed(){
+    let game_def = Game::setup_seeded(vec!(1, 2, 3, 4), 1, Rules::default(), 42).unwrap();
+    let record = GameRecord::new(&game_def).unwrap();
+
+    assert_eq!(record.seed, Some(42));
+    assert!(record.to_string().contains("[Seed \"42\"]"));
+}
@@ -0,0 +1,75 @@
+use game::game::Game;
+use game::player_move::build_move;
+use game::probability::*;
+use cards::card::*;
+use cards::types::*;
+
+#[test]
+pub fn small_cases_are_counted_exactly(){
+    let ace = build_move(vec!(card!(Ace, Hearts))).unwrap();
+    let unseen = vec!(card!(Two, Spades), card!(Three, Diamonds));
+
+    let estimates = Estimator::default().estimate(&unseen, &[(1, 1)], ace);
+
+    assert_eq!(estimates, vec!(BeatEstimate{ player: 1, probability: 0.5, exact: true }));
+}
+
+#[test]
+pub fn jokers_count_as_wildcards(){
+    let two = build_move(vec!(card!(Two, Hearts))).unwrap();
+    let unseen = vec!(PlayerCard::Joker(0), card!(Three, Diamonds), card!(Four, Clubs), card!(Five, Clubs));
+
+    let estimates = Estimator::default().estimate(&unseen, &[(1, 2), (2, 1)], two);
+
+    assert_eq!(estimates[0].probability, 0.5);
+    assert_eq!(estimates[1].probability, 0.25);
+}
+
+#[test]
+pub fn reversal_is_respected(){
+    let four = build_move(vec!(card!(Four, Clubs, true))).unwrap();
+    let unseen = vec!(card!(Three, Diamonds, true), card!(King, Spades, true));
+
+    let estimates = Estimator::default().estimate(&unseen, &[(1, 1)], four);
+
+    assert_eq!(estimates[0].probability, 0.5);
+}
+
+#[test]
+pub fn five_card_tricks_can_be_completed_with_wildcards(){
+    let flush = build_move(vec!(
+        card!(Three, Hearts), card!(Six, Hearts), card!(Nine, Hearts), card!(Jack, Hearts), card!(Ace, Hearts))).unwrap();
+    let unseen = vec!(
+        card!(Five, Spades), card!(Six, Spades), card!(Seven, Spades), card!(Eight, Spades), PlayerCard::Joker(0));
+
+    let with_joker = Estimator::default().estimate(&unseen, &[(1, 5)], flush);
+    let without_joker = Estimator::default().estimate(&unseen[..4], &[(1, 4)], flush);
+
+    assert_eq!(with_joker[0].probability, 1.0);
+    assert_eq!(without_joker[0].probability, 0.0);
+}
+
+#[test]
+pub fn large_cases_are_sampled_reproducibly(){
+    let game_def = Game::setup(vec!(0, 1, 2, 3), 1).unwrap();
+    let proposed = build_move(vec!(card!(King, Spades))).unwrap();
+
+    let estimator = Estimator{ samples: 200, .. Estimator::default() };
+    let first = estimator.estimate_for_player(&game_def, 0, proposed).unwrap();
+    let second = estimator.estimate_for_player(&game_def, 0, proposed).unwrap();
+
+    assert_eq!(first.len(), 3);
+    assert!(first.iter().all(|e| !e.exact && e.probability > 0.5));
+    assert_eq!(first, second);
+}
+
+#[test]
+pub fn sampling_draws_at_least_one_hand(){
+    let ace = build_move(vec!(card!(Ace, Hearts))).unwrap();
+    let unseen = vec!(card!(Two, Spades), card!(Two, Hearts));
+
+    let estimator = Estimator{ exact_limit: 0, samples: 0, .. Estimator::default() };
+    let estimates = estimator.estimate(&unseen, &[(1, 1)], ace);
+
+    assert_eq!(estimates, vec!(BeatEstimate{ player: 1, probability: 1.0, exact: false }));
+}
@@ -0,0 +1,173 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use game::game::{ Game, GameDefinition, Outcome };
+use game::player::Player;
+use game::player_move::Move;
+use game::round::Round;
+use game::rules::{ Rules, Variant };
+use game::tien_len::{ Combination, TienLenMove };
+use game::runner::{ GameEvent, GameRunner };
+use cards::card::*;
+use cards::types::*;
+
+fn get_runner(game_def: GameDefinition) -> (GameRunner, Rc<RefCell<Vec<GameEvent>>>) {
+    let events = Rc::new(RefCell::new(vec!()));
+    let seen = events.clone();
+
+    let mut runner = GameRunner::new(game_def);
+    runner.subscribe(Box::new(move |e: &GameEvent| seen.borrow_mut().push(e.clone())));
+
+    (runner, events)
+}
+
+fn get_game() -> GameDefinition {
+    let hands = vec!(
+        vec!(card!(Three, Clubs), card!(Four, Clubs)),
+        vec!(card!(Five, Clubs), card!(Six, Clubs)),
+        vec!(card!(Seven, Clubs), card!(Eight, Clubs))
+    );
+
+    Game::setup_with_hands(vec!(0, 1, 2), hands, Rules::default()).unwrap()
+}
+
+#[test]
+pub fn starting_announces_the_first_turn(){
+    let (mut runner, events) = get_runner(get_game());
+
+    runner.start().unwrap();
+
+    assert_eq!(*events.borrow(), vec!(GameEvent::TurnStarted(0)));
+}
+
+#[test]
+pub fn a_move_is_reported_before_the_next_turn(){
+    let (mut runner, events) = get_runner(get_game());
+
+    runner.player_move(0, vec!(card!(Three, Clubs))).unwrap();
+
+    assert_eq!(*events.borrow(), vec!(
+        GameEvent::MovePlayed {
+            player: 0,
+            cards: vec!(card!(Three, Clubs)),
+            player_move: Move::Single(Card::new(Rank::Three, Suit::Clubs, false))
+        },
+        GameEvent::TurnStarted(1)
+    ));
+}
+
+#[test]
+pub fn a_tien_len_sequence_is_reported_whole(){
+    let hands = vec!(
+        vec!(card!(Three, Spades), card!(Four, Clubs), card!(Five, Hearts), card!(King, Clubs)),
+        vec!(card!(Six, Spades), card!(Seven, Clubs))
+    );
+    let rules = Rules{ variant: Variant::TienLen, ..Rules::default() };
+    let (mut runner, events) = get_runner(Game::setup_with_hands(vec!(0, 1), hands, rules).unwrap());
+
+    runner.player_move(0, vec!(card!(Three, Spades), card!(Four, Clubs), card!(Five, Hearts))).unwrap();
+
+    let first = events.borrow()[0].clone();
+    match first {
+        GameEvent::MovePlayed { player, player_move, .. } => {
+            assert_eq!(player, 0);
+            assert_eq!(TienLenMove::from_move(player_move).unwrap().combination, Combination::Sequence(3));
+        },
+        e => panic!("unexpected event {:?}", e)
+    }
+}
+
+#[test]
+pub fn a_cleared_round_is_reported(){
+    let (mut runner, events) = get_runner(get_game());
+
+    runner.player_move(0, vec!(card!(Three, Clubs))).unwrap();
+    runner.player_move(1, vec!()).unwrap();
+    runner.player_move(2, vec!()).unwrap();
+
+    let events = events.borrow();
+    assert_eq!(events[events.len() - 3..].to_vec(), vec!(
+        GameEvent::Passed(2),
+        GameEvent::RoundCleared(0),
+        GameEvent::TurnStarted(0)
+    ));
+}
+
+#[test]
+pub fn invalid_moves_are_not_reported(){
+    let (mut runner, events) = get_runner(get_game());
+
+    assert!(runner.player_move(1, vec!(card!(Five, Clubs))).is_err());
+    assert!(events.borrow().is_empty());
+    assert_eq!(runner.get_game().history.len(), 0);
+}
+
+#[test]
+pub fn every_observer_is_told(){
+    let (mut runner, first) = get_runner(get_game());
+    let second = Rc::new(RefCell::new(0));
+    let count = second.clone();
+    runner.subscribe(Box::new(move |_: &GameEvent| *count.borrow_mut() += 1));
+
+    runner.player_move(0, vec!(card!(Three, Clubs))).unwrap();
+
+    assert_eq!(first.borrow().len(), 2);
+    assert_eq!(*second.borrow(), 2);
+}
+
+#[test]
+pub fn finishing_and_reversing_are_reported(){
+    let quad = vec!(card!(Nine, Clubs), card!(Nine, Hearts), card!(Nine, Diamonds), card!(Nine, Spades), card!(Four, Hearts));
+    let players = vec!(
+        Player::new(0).set_hand(quad.clone()),
+        Player::new(1).set_hand(vec!(card!(Five, Clubs))),
+        Player::new(2).set_hand(vec!(card!(Six, Clubs), card!(Seven, Clubs)))
+    );
+    let game_def = GameDefinition::new(
+        players,
+        Round::new(vec!(0, 1, 2), 0, Move::Pass, 0, false),
+        vec!(),
+        false);
+    let (mut runner, events) = get_runner(game_def);
+
+    runner.player_move(0, quad).unwrap();
+
+    let events = events.borrow();
+    assert!(events.contains(&GameEvent::CardsReversed(true)));
+    assert!(events.contains(&GameEvent::PlayerFinished{ player: 0, place: 1 }));
+}
+
+#[test]
+pub fn the_end_of_the_game_is_reported(){
+    let players = vec!(
+        Player::new(0).set_hand(vec!(card!(Two, Spades))),
+        Player::new(1).set_hand(vec!(card!(Five, Clubs))),
+        Player::new(2).set_hand(vec!())
+    );
+    let game_def = GameDefinition::new(
+        players,
+        Round::new(vec!(0, 1), 0, Move::Pass, 0, false),
+        vec!(2),
+        false);
+    let (mut runner, events) = get_runner(game_def);
+
+    runner.player_move(0, vec!(card!(Two, Spades))).unwrap();
+
+    assert_eq!(events.borrow().last(), Some(&GameEvent::GameOver(Outcome{ finishing_order: vec!(2, 0, 1) })));
+}
+
+#[test]
+pub fn a_last_card_announcement_is_passed_on(){
+    let rules = Rules{ announce_last_card: true, ..Rules::default() };
+    let hands = vec!(
+        vec!(card!(Three, Clubs), card!(Four, Clubs)),
+        vec!(card!(Five, Clubs), card!(Six, Clubs), card!(Seven, Clubs)));
+    let (mut runner, events) = get_runner(Game::setup_with_hands(vec!(0, 1), hands, rules).unwrap());
+
+    assert!(runner.player_move(0, vec!(card!(Three, Clubs))).is_err());
+    assert!(events.borrow().is_empty());
+
+    let game_def = runner.player_move_announcing(0, vec!(card!(Three, Clubs)), true).unwrap();
+    assert!(game_def.history[0].last_card);
+    assert_eq!(events.borrow().len(), 2);
+}
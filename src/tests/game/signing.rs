@@ -0,0 +1,105 @@
+use game::game::{ Game, GameDefinition };
+use game::signing::Keyring;
+use cards::card::*;
+use cards::types::*;
+
+fn get_game() -> GameDefinition {
+    Game::setup_seeded(vec!(0, 1, 2, 3), 1, Default::default(), 7).unwrap()
+}
+
+#[test]
+pub fn a_signed_game_can_be_loaded(){
+    let keys = Keyring::new("2024-01", b"secret");
+    let signed = keys.sign(&get_game()).unwrap();
+
+    assert_eq!(signed.key_id, "2024-01");
+    assert!(keys.load(&signed).is_ok());
+}
+
+#[test]
+pub fn signed_games_survive_a_json_round_trip(){
+    let keys = Keyring::new("a", b"secret");
+    let signed = keys.sign(&get_game()).unwrap();
+
+    let json = ::serde_json::to_string(&signed).unwrap();
+    let returned = ::serde_json::from_str(&json).unwrap();
+
+    assert!(keys.verify(&returned).is_ok());
+}
+
+#[test]
+pub fn a_tampered_game_is_refused(){
+    let keys = Keyring::new("a", b"secret");
+    let mut signed = keys.sign(&get_game()).unwrap();
+
+    let mut game: GameDefinition = ::serde_json::from_str(&signed.state).unwrap();
+    let mut hand = game.players[0].get_hand();
+    hand.push(card!(Two, Spades));
+    game.players[0] = game.players[0].set_hand(hand);
+    signed.state = ::serde_json::to_string(&game).unwrap();
+
+    assert_eq!(keys.load(&signed).err(), Some("Signature does not match"));
+}
+
+#[test]
+pub fn the_state_is_verified_exactly_as_it_was_signed(){
+    let keys = Keyring::new("a", b"secret");
+    let mut signed = keys.sign(&get_game()).unwrap();
+
+    assert_eq!(keys.verify(&signed).unwrap().players, get_game().players);
+
+    // the same game, written differently
+    signed.state = format!(" {}", signed.state);
+    assert!(::serde_json::from_str::<GameDefinition>(&signed.state).is_ok());
+    assert_eq!(keys.verify(&signed).err(), Some("Signature does not match"));
+}
+
+#[test]
+pub fn a_game_signed_with_another_secret_is_refused(){
+    let keys = Keyring::new("a", b"secret");
+    let forged = Keyring::new("a", b"guess").sign(&get_game()).unwrap();
+
+    assert_eq!(keys.verify(&forged).err(), Some("Signature does not match"));
+}
+
+#[test]
+pub fn a_garbled_signature_is_refused(){
+    let keys = Keyring::new("a", b"secret");
+    let mut signed = keys.sign(&get_game()).unwrap();
+    signed.signature = "not hex".to_string();
+
+    assert_eq!(keys.verify(&signed).err(), Some("Signature does not match"));
+}
+
+#[test]
+pub fn old_keys_are_accepted_after_rotation(){
+    let old = Keyring::new("a", b"first");
+    let signed = old.sign(&get_game()).unwrap();
+
+    let keys = old.rotate("b", b"second");
+
+    assert!(keys.verify(&signed).is_ok());
+    assert!(keys.needs_resign(&signed));
+    assert_eq!(keys.sign(&get_game()).unwrap().key_id, "b");
+    assert_eq!(keys.get_current_key(), "b");
+}
+
+#[test]
+pub fn retired_keys_are_refused(){
+    let old = Keyring::new("a", b"first");
+    let signed = old.sign(&get_game()).unwrap();
+
+    let keys = old.rotate("b", b"second").retire("a").unwrap();
+
+    assert_eq!(keys.verify(&signed).err(), Some("Unknown signing key"));
+    assert!(keys.retire("b").is_err());
+}
+
+#[test]
+pub fn a_signature_cannot_be_moved_to_another_key(){
+    let keys = Keyring::new("a", b"secret").rotate("b", b"secret");
+    let mut signed = keys.sign(&get_game()).unwrap();
+    signed.key_id = "a".to_string();
+
+    assert!(keys.verify(&signed).is_err());
+}
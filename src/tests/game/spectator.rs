@@ -0,0 +1,80 @@
+use game::game::{ Game, GameDefinition };
+use game::player_move::Move;
+use game::spectator::*;
+use cards::card::*;
+use cards::types::*;
+use game::player::Player;
+use game::round::Round;
+
+fn get_game() -> Game {
+    let player1 = Player::new(0).set_hand(vec!(card!(Four, Hearts), card!(Five, Clubs), card!(Three, Clubs)));
+    let player2 = Player::new(1).set_hand(vec!(card!(Three, Diamonds), card!(Two, Spades)));
+
+    let game_def = GameDefinition::new(
+        vec!(player1, player2),
+        Round::new(vec!(0, 1), 0, Move::Pass, 0, true),
+        vec!(),
+        false);
+
+    let game = Game::load(game_def).unwrap();
+    let game = Game::load(game.player_move(0, vec!(card!(Three, Clubs))).unwrap()).unwrap();
+    Game::load(game.player_move(1, vec!(card!(Three, Diamonds))).unwrap()).unwrap()
+}
+
+#[test]
+pub fn public_views_show_counts_but_not_hands(){
+    let view = SpectatorView::new(&get_game(), RevealPolicy::PublicOnly).unwrap();
+
+    assert_eq!(view.seats[0].remaining_cards, 2);
+    assert_eq!(view.seats[1].remaining_cards, 1);
+    assert!(view.seats.iter().all(|s| s.hand.is_none()));
+    assert_eq!(view.last_move, Move::Single(card!(Three, Diamonds).to_card()));
+    assert_eq!(view.next_player, Some(0));
+    assert_eq!(view.history.len(), 2);
+}
+
+#[test]
+pub fn history_in_a_view_carries_no_hidden_state(){
+    let view = SpectatorView::new(&get_game(), RevealPolicy::PublicOnly).unwrap();
+
+    assert!(view.history.iter().all(|t| t.previous.is_none()));
+    assert!(!view.to_json().unwrap().contains("Five"));
+}
+
+#[test]
+pub fn delayed_views_show_the_whole_table_as_it_was(){
+    let view = SpectatorView::new(&get_game(), RevealPolicy::Delayed(1)).unwrap();
+
+    assert_eq!(view.history.len(), 1);
+    assert_eq!(view.seats[1].hand, Some(vec!(card!(Three, Diamonds), card!(Two, Spades))));
+    assert_eq!(view.last_move, Move::Single(card!(Three, Clubs).to_card()));
+}
+
+#[test]
+pub fn delayed_views_hide_hands_until_enough_moves_are_made(){
+    let view = SpectatorView::new(&get_game(), RevealPolicy::Delayed(3)).unwrap();
+
+    assert_eq!(view.history.len(), 0);
+    assert!(view.seats.iter().all(|s| s.hand.is_none()));
+}
+
+#[test]
+pub fn a_delay_of_no_moves_is_refused(){
+    assert_eq!(SpectatorView::new(&get_game(), RevealPolicy::Delayed(0)).err(),
+               Some("A delayed view must be at least one move behind"));
+}
+
+#[test]
+pub fn post_game_views_only_reveal_finished_games(){
+    let game = get_game();
+    let view = SpectatorView::new(&game, RevealPolicy::PostGame).unwrap();
+    assert!(view.seats.iter().all(|s| s.hand.is_none()));
+
+    let game = Game::load(game.player_move(0, vec!(card!(Four, Hearts))).unwrap()).unwrap();
+    let game = Game::load(game.player_move(1, vec!(card!(Two, Spades))).unwrap()).unwrap();
+    let view = SpectatorView::new(&game, RevealPolicy::PostGame).unwrap();
+
+    assert!(view.finished);
+    assert_eq!(view.next_player, None);
+    assert_eq!(view.seats[0].hand, Some(vec!(card!(Five, Clubs))));
+}
@@ -0,0 +1,201 @@
+use std::env;
+use std::fs;
+use std::process;
+use std::path::Path;
+
+use game::game::{ Game, GameDefinition };
+use game::player_move::{Move, build_move};
+use game::store::*;
+use game::rules::Rules;
+use cards::card::*;
+use cards::types::*;
+use game::player::Player;
+use game::round::Round;
+
+fn get_game() -> GameDefinition {
+    let player1 = Player::new(0).set_hand(vec!(card!(Four, Hearts), card!(Five, Clubs)));
+    let player2 = Player::new(1).set_hand(vec!(card!(Three, Diamonds), card!(Six, Spades)));
+
+    let single_three = build_move(vec!(card!(Three, Clubs))).unwrap();
+
+    GameDefinition::new(
+        vec!(player1, player2),
+        Round::new(vec!(0, 1), 0, single_three, 0, false),
+        vec!(),
+        false)
+}
+
+fn get_finished_game() -> GameDefinition {
+    let player1 = Player::new(0).set_hand(vec!());
+    let player2 = Player::new(1).set_hand(vec!(card!(Three, Diamonds)));
+
+    GameDefinition::new(
+        vec!(player1, player2),
+        Round::new(vec!(1), 1, Move::Pass, 0, false),
+        vec!(0),
+        false)
+}
+
+fn temp_dir(name: &str) -> String {
+    let dir = env::temp_dir().join(format!("pusoy_dos_{}_{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir.to_str().unwrap().to_string()
+}
+
+#[test]
+pub fn memory_store_assigns_ids_on_create(){
+    let mut store = MemoryStore::new();
+
+    let first = store.create(get_game()).unwrap();
+    let second = store.create(get_game()).unwrap();
+
+    assert_eq!(first.id, 0);
+    assert_eq!(second.id, 1);
+    assert_eq!(first.version, 0);
+}
+
+#[test]
+pub fn memory_store_loads_what_was_saved(){
+    let mut store = MemoryStore::new();
+    let stored = store.create(get_game()).unwrap();
+
+    let game = Game::load(stored.game).unwrap();
+    let next = game.player_move(0, vec!(card!(Four, Hearts))).unwrap();
+
+    let saved = store.save(stored.id, next, 0).unwrap();
+    let loaded = store.load(stored.id).unwrap();
+
+    assert_eq!(saved.version, 1);
+    assert_eq!(loaded.version, 1);
+    assert_eq!(loaded.game.players[0].get_hand(), vec!(card!(Five, Clubs)));
+}
+
+#[test]
+pub fn saving_a_stale_version_is_rejected(){
+    let mut store = MemoryStore::new();
+    let stored = store.create(get_game()).unwrap();
+
+    store.save(stored.id, get_game(), 0).unwrap();
+
+    assert!(store.save(stored.id, get_game(), 0).is_err());
+}
+
+#[test]
+pub fn loading_an_unknown_game_fails(){
+    let store = MemoryStore::new();
+
+    assert!(store.load(7).is_err());
+}
+
+#[test]
+pub fn only_unfinished_games_are_active(){
+    let mut store = MemoryStore::new();
+    let active = store.create(get_game()).unwrap();
+    store.create(get_finished_game()).unwrap();
+
+    assert_eq!(store.list_active().unwrap(), vec!(active.id));
+}
+
+#[test]
+pub fn play_move_applies_and_saves_a_move(){
+    let mut store = MemoryStore::new();
+    let stored = store.create(get_game()).unwrap();
+
+    let played = play_move(&mut store, stored.id, 0, 0, vec!(card!(Four, Hearts))).unwrap();
+
+    assert_eq!(played.version, 1);
+    assert_eq!(played.game.round.get_next_player(), 1);
+}
+
+#[test]
+pub fn concurrent_moves_against_the_same_version_conflict(){
+    let mut store = MemoryStore::new();
+    let stored = store.create(get_game()).unwrap();
+
+    play_move(&mut store, stored.id, 0, 0, vec!(card!(Four, Hearts))).unwrap();
+    let second = play_move(&mut store, stored.id, 0, 0, vec!(card!(Five, Clubs)));
+
+    assert!(second.is_err());
+    assert_eq!(store.load(stored.id).unwrap().version, 1);
+}
+
+#[test]
+pub fn invalid_moves_are_not_saved(){
+    let mut store = MemoryStore::new();
+    let stored = store.create(get_game()).unwrap();
+
+    assert!(play_move(&mut store, stored.id, 0, 1, vec!(card!(Six, Spades))).is_err());
+    assert_eq!(store.load(stored.id).unwrap().version, 0);
+}
+
+#[test]
+pub fn file_store_round_trips_games(){
+    let dir = temp_dir("round_trip");
+    let mut store = FileStore::new(dir.clone()).unwrap();
+
+    let stored = store.create(get_game()).unwrap();
+    let played = play_move(&mut store, stored.id, 0, 0, vec!(card!(Four, Hearts))).unwrap();
+
+    let reopened = FileStore::new(dir.clone()).unwrap();
+    let loaded = reopened.load(stored.id).unwrap();
+
+    assert_eq!(loaded.version, played.version);
+    assert_eq!(loaded.game.round, played.game.round);
+    assert_eq!(loaded.game.players, played.game.players);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+pub fn file_store_rejects_stale_versions_and_lists_active_games(){
+    let dir = temp_dir("active");
+    let mut store = FileStore::new(dir.clone()).unwrap();
+
+    let active = store.create(get_game()).unwrap();
+    let finished = store.create(get_finished_game()).unwrap();
+
+    assert_eq!(finished.id, active.id + 1);
+    assert_eq!(store.list_active().unwrap(), vec!(active.id));
+
+    store.save(active.id, get_game(), 0).unwrap();
+    assert!(store.save(active.id, get_game(), 0).is_err());
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+pub fn file_store_refuses_a_save_while_the_game_is_locked(){
+    let dir = temp_dir("locked");
+    let mut store = FileStore::new(dir.clone()).unwrap();
+    let stored = store.create(get_game()).unwrap();
+
+    let lock = Path::new(&dir).join(format!("{}.json.lock", stored.id));
+    fs::write(&lock, "").unwrap();
+
+    assert_eq!(store.save(stored.id, get_game(), 0).err(),
+               Some("Game is being saved by another writer"));
+    assert_eq!(store.load(stored.id).unwrap().version, 0);
+
+    fs::remove_file(&lock).unwrap();
+    assert_eq!(store.save(stored.id, get_game(), 0).unwrap().version, 1);
+    assert!(!lock.exists());
+
+    let next = store.create(get_game()).unwrap();
+    assert_eq!(next.id, stored.id + 1);
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+pub fn play_move_passes_on_a_last_card_announcement(){
+    let rules = Rules{ announce_last_card: true, ..Rules::default() };
+    let mut store = MemoryStore::new();
+    let stored = store.create(get_game().with_rules(rules)).unwrap();
+
+    assert_eq!(play_move(&mut store, stored.id, 0, 0, vec!(card!(Four, Hearts))).err(),
+               Some("You must announce your last card"));
+
+    let played = play_move_announcing(&mut store, stored.id, 0, 0, vec!(card!(Four, Hearts)), true).unwrap();
+    assert!(played.game.history[0].last_card);
+}
@@ -0,0 +1,118 @@
+use game::game::{Game, Outcome};
+use game::rules::Rules;
+use game::teams::*;
+use cards::card::*;
+use cards::types::*;
+
+fn get_rules(partner_takes_lead: bool) -> Rules {
+    Rules{
+        teams: seated_opposite(&[1, 2, 3, 4]).unwrap(),
+        partner_takes_lead,
+        ..Rules::default()
+    }
+}
+
+fn get_game(last_card: PlayerCard, partner_takes_lead: bool) -> Game {
+    let hands = vec!(
+        vec!(card!(Three, Clubs), last_card),
+        vec!(card!(Four, Clubs), card!(Five, Clubs)),
+        vec!(card!(Six, Clubs), card!(Seven, Clubs)),
+        vec!(card!(Eight, Clubs), card!(Nine, Clubs)));
+
+    Game::load(Game::setup_with_hands(vec!(1, 2, 3, 4), hands, get_rules(partner_takes_lead)).unwrap()).unwrap()
+}
+
+fn play(game: Game, moves: Vec<(u64, Vec<PlayerCard>)>) -> Game {
+    moves.into_iter().fold(game, |game, (player, cards)| {
+        Game::load(game.player_move(player, cards).unwrap()).unwrap()
+    })
+}
+
+fn go_out_and_pass(game: Game, last_card: PlayerCard) -> Game {
+    play(game, vec!((1, vec!(card!(Three, Clubs))), (2, vec!()), (3, vec!()), (4, vec!()),
+                    (1, vec!(last_card)), (2, vec!()), (3, vec!()), (4, vec!())))
+}
+
+#[test]
+pub fn partners_are_seated_opposite(){
+    let teams = seated_opposite(&[1, 2, 3, 4, 5, 6]).unwrap();
+
+    assert_eq!(teams, vec!(Team{ players: vec!(1, 4) }, Team{ players: vec!(2, 5) }, Team{ players: vec!(3, 6) }));
+    assert!(seated_opposite(&[1, 2, 3]).is_err());
+    assert!(seated_opposite(&[1, 2]).is_err());
+}
+
+#[test]
+pub fn every_player_must_be_in_one_team(){
+    let rules = Rules{ teams: vec!(Team{ players: vec!(1, 3) }, Team{ players: vec!(2, 3) }), ..Rules::default() };
+    let game = Game::setup_with_rules(vec!(1, 2, 3, 4), 1, rules);
+
+    assert_eq!(game.err(), Some("Every player must be in exactly one team"));
+}
+
+#[test]
+pub fn the_next_seat_leads_after_a_player_goes_out(){
+    let game = go_out_and_pass(get_game(card!(King, Hearts), false), card!(King, Hearts));
+
+    assert_eq!(game.get_next_player().unwrap().get_id(), 2);
+}
+
+#[test]
+pub fn a_partner_can_take_over_the_lead(){
+    let game = go_out_and_pass(get_game(card!(King, Hearts), true), card!(King, Hearts));
+
+    assert_eq!(game.get_next_player().unwrap().get_id(), 3);
+}
+
+#[test]
+pub fn a_partner_takes_the_lead_after_an_unbeatable_last_card(){
+    let game = get_game(card!(Two, Spades), true);
+    let game = play(game, vec!((1, vec!(card!(Three, Clubs))), (2, vec!(card!(Four, Clubs))), (3, vec!()), (4, vec!()),
+                               (1, vec!(card!(Two, Spades)))));
+
+    assert_eq!(game.get_next_player().unwrap().get_id(), 3);
+}
+
+#[test]
+pub fn teams_are_scored_together(){
+    let outcome = Outcome{ finishing_order: vec!(1, 2, 3, 4) };
+    let team_outcome = get_team_outcome(&get_rules(false).teams, &outcome);
+
+    assert_eq!(team_outcome.scores, vec!(4, 2));
+    assert_eq!(team_outcome.finishing_order, vec!(0, 1));
+}
+
+#[test]
+pub fn games_without_teams_have_no_team_outcome(){
+    let game = Game::load(Game::setup_with_hands(vec!(1, 2), vec!(vec!(card!(Three, Clubs)), vec!()), Rules::default()).unwrap()).unwrap();
+
+    assert!(game.get_outcome().is_some());
+    assert_eq!(game.get_team_outcome(), None);
+}
+
+#[test]
+pub fn team_views_hide_the_partners_hand(){
+    let game = go_out_and_pass(get_game(card!(King, Hearts), true), card!(King, Hearts));
+    let view = TeamView::new(&game, 3).unwrap();
+
+    assert_eq!(view.team, 0);
+    assert_eq!(view.hand, vec!(card!(Six, Clubs), card!(Seven, Clubs)));
+    assert_eq!(view.finished_teams, Vec::<usize>::new());
+    assert_eq!(view.next_player, Some(3));
+
+    let partner = view.seats.iter().find(|s| s.id == 1).unwrap();
+    assert!(partner.partner);
+    assert_eq!(partner.remaining_cards, 0);
+    assert!(!view.seats.iter().find(|s| s.id == 2).unwrap().partner);
+
+    let json = ::serde_json::to_string(&view).unwrap();
+    assert!(!json.contains("Eight"));
+    assert!(!json.contains("Four"));
+}
+
+#[test]
+pub fn players_outside_teams_have_no_team_view(){
+    let game = get_game(card!(King, Hearts), true);
+
+    assert!(TeamView::new(&game, 9).is_err());
+}
@@ -0,0 +1,226 @@
+use game::game::{ Game, GameDefinition };
+use game::rules::{ Rules, Variant };
+use game::tien_len::{ Combination, TienLenMove };
+use game::advisor;
+use game::describe::describe_move;
+use game::spectator::{ SpectatorView, RevealPolicy };
+use game::tracker::CardTracker;
+use game::player::Player;
+use game::player_move::Move;
+use game::round::Round;
+use cards::card::*;
+use cards::types::*;
+
+fn get_move(cards: Vec<PlayerCard>) -> TienLenMove {
+    TienLenMove::build(&cards).unwrap()
+}
+
+fn get_rules() -> Rules {
+    Rules{ variant: Variant::TienLen, ..Rules::default() }
+}
+
+#[test]
+pub fn sequences_can_be_any_length_from_three(){
+    let three = get_move(vec!(card!(Five, Hearts), card!(Three, Spades), card!(Four, Clubs)));
+    let six = get_move(vec!(card!(Nine, Hearts), card!(Ten, Spades), card!(Jack, Clubs),
+                            card!(Queen, Clubs), card!(King, Diamonds), card!(Ace, Spades)));
+
+    assert_eq!(three.combination, Combination::Sequence(3));
+    assert_eq!(three.get_top_card(), Card::new(Rank::Five, Suit::Hearts, false));
+    assert_eq!(six.combination, Combination::Sequence(6));
+}
+
+#[test]
+pub fn sequences_cannot_include_twos(){
+    assert!(TienLenMove::build(&[card!(King, Hearts), card!(Ace, Spades), card!(Two, Clubs)]).is_none());
+    assert!(TienLenMove::build(&[card!(Three, Hearts), card!(Four, Spades), card!(Six, Clubs)]).is_none());
+}
+
+#[test]
+pub fn consecutive_pairs_are_recognised(){
+    let pairs = get_move(vec!(card!(Five, Hearts), card!(Five, Spades), card!(Six, Clubs),
+                              card!(Six, Hearts), card!(Seven, Spades), card!(Seven, Diamonds)));
+
+    assert_eq!(pairs.combination, Combination::ConsecutivePairs(3));
+    assert_eq!(get_move(vec!(card!(Nine, Hearts); 4)).combination, Combination::FourOfAKind);
+}
+
+#[test]
+pub fn hearts_are_the_highest_suit(){
+    let hearts = get_move(vec!(card!(Three, Hearts)));
+    let diamonds = get_move(vec!(card!(Three, Diamonds)));
+    let clubs = get_move(vec!(card!(Three, Clubs)));
+    let spades = get_move(vec!(card!(Three, Spades)));
+
+    assert!(hearts.beats(&diamonds));
+    assert!(diamonds.beats(&clubs));
+    assert!(clubs.beats(&spades));
+    assert!(!spades.beats(&hearts));
+}
+
+#[test]
+pub fn sequences_must_match_in_length(){
+    let low = get_move(vec!(card!(Three, Spades), card!(Four, Clubs), card!(Five, Hearts)));
+    let high = get_move(vec!(card!(Six, Spades), card!(Seven, Clubs), card!(Eight, Hearts)));
+    let long = get_move(vec!(card!(Six, Spades), card!(Seven, Clubs), card!(Eight, Hearts), card!(Nine, Hearts)));
+
+    assert!(high.beats(&low));
+    assert!(!long.beats(&low));
+}
+
+#[test]
+pub fn twos_can_be_chopped(){
+    let two = get_move(vec!(card!(Two, Hearts)));
+    let pair_of_twos = get_move(vec!(card!(Two, Hearts), card!(Two, Spades)));
+    let three_pairs = get_move(vec!(card!(Three, Hearts), card!(Three, Spades), card!(Four, Clubs),
+                                    card!(Four, Hearts), card!(Five, Spades), card!(Five, Diamonds)));
+    let four_pairs = get_move(vec!(card!(Three, Hearts), card!(Three, Spades), card!(Four, Clubs),
+                                   card!(Four, Hearts), card!(Five, Spades), card!(Five, Diamonds),
+                                   card!(Six, Spades), card!(Six, Diamonds)));
+    let four = get_move(vec!(card!(Three, Hearts), card!(Three, Spades), card!(Three, Clubs), card!(Three, Diamonds)));
+
+    assert!(three_pairs.beats(&two));
+    assert!(four.beats(&two));
+    assert!(four.beats(&three_pairs));
+    assert!(four_pairs.beats(&pair_of_twos));
+    assert!(four_pairs.beats(&four));
+    assert!(!three_pairs.beats(&pair_of_twos));
+    assert!(!four.beats(&get_move(vec!(card!(Ace, Hearts)))));
+}
+
+#[test]
+pub fn a_tien_len_deal_has_no_jokers(){
+    let game_def = Game::setup_with_rules(vec!(0, 1, 2, 3), 1, get_rules()).unwrap();
+
+    for player in &game_def.players {
+        assert_eq!(player.remaining_cards(), 13);
+        assert!(!player.get_hand().iter().any(|c| matches!(*c, PlayerCard::Joker(_))));
+    }
+
+    let first = Game::load(game_def.clone()).unwrap().get_next_player().unwrap();
+    assert!(first.get_hand().contains(&card!(Three, Spades)));
+}
+
+fn get_game() -> GameDefinition {
+    let hands = vec!(
+        vec!(card!(Three, Spades), card!(Four, Clubs), card!(Five, Hearts), card!(King, Clubs)),
+        vec!(card!(Six, Spades), card!(Seven, Clubs), card!(Eight, Hearts), card!(Three, Clubs)),
+        vec!(card!(Nine, Spades), card!(Ten, Clubs), card!(Jack, Hearts), card!(Queen, Clubs), card!(Four, Hearts))
+    );
+
+    Game::setup_with_hands(vec!(0, 1, 2), hands, get_rules()).unwrap()
+}
+
+#[test]
+pub fn sequences_can_be_played(){
+    let game = Game::load(get_game()).unwrap();
+    let game_def = game.player_move(0, vec!(card!(Three, Spades), card!(Four, Clubs), card!(Five, Hearts))).unwrap();
+
+    let game = Game::load(game_def).unwrap();
+    assert!(game.player_move(1, vec!(card!(Three, Clubs))).is_err());
+    assert!(game.player_move(1, vec!(card!(Six, Spades), card!(Seven, Clubs), card!(Eight, Hearts))).is_ok());
+}
+
+#[test]
+pub fn the_first_play_must_include_the_three_of_spades(){
+    let game = Game::load(get_game()).unwrap();
+
+    assert!(game.player_move(0, vec!(card!(King, Clubs))).is_err());
+}
+
+#[test]
+pub fn cards_are_not_reversed_in_tien_len(){
+    let hands = vec!(
+        vec!(card!(Three, Spades), card!(Three, Clubs), card!(Three, Diamonds), card!(Three, Hearts), card!(Four, Clubs)),
+        vec!(card!(Six, Spades), card!(Seven, Clubs))
+    );
+    let game = Game::load(Game::setup_with_hands(vec!(0, 1), hands, get_rules()).unwrap()).unwrap();
+
+    let game_def = game.player_move(0, vec!(card!(Three, Spades), card!(Three, Clubs), card!(Three, Diamonds), card!(Three, Hearts))).unwrap();
+
+    assert!(!game_def.reversed);
+}
+
+#[test]
+pub fn legal_moves_include_sequences(){
+    let game = Game::load(get_game()).unwrap();
+    let moves = game.get_legal_moves(0);
+
+    assert!(moves.contains(&vec!(card!(Three, Spades), card!(Four, Clubs), card!(Five, Hearts))));
+    assert!(moves.contains(&vec!(card!(Three, Spades))));
+    assert!(!moves.contains(&vec!(card!(King, Clubs))));
+    assert!(!moves.contains(&vec!()));
+}
+
+#[test]
+pub fn jokers_cannot_be_dealt_by_hand(){
+    let hands = vec!(vec!(card!(Three, Spades), PlayerCard::Joker(0)), vec!(card!(Four, Clubs)));
+
+    assert_eq!(Game::setup_with_hands(vec!(0, 1), hands, get_rules()).err(), Some("Tiến lên is played without jokers"));
+}
+
+#[test]
+pub fn the_round_keeps_the_whole_sequence(){
+    let game = Game::load(get_game()).unwrap();
+    let game = Game::load(game.player_move(0, vec!(card!(Three, Spades), card!(Four, Clubs), card!(Five, Hearts))).unwrap()).unwrap();
+
+    let last_move = game.get_last_move();
+    assert_eq!(last_move.get_cards(), vec!(Card::new(Rank::Three, Suit::Spades, false),
+                                           Card::new(Rank::Four, Suit::Clubs, false),
+                                           Card::new(Rank::Five, Suit::Hearts, false)));
+    assert_eq!(TienLenMove::from_move(last_move).unwrap().combination, Combination::Sequence(3));
+    assert_eq!(describe_move(last_move), "Sequence of 3, 5 high");
+    assert_eq!(advisor::describe(last_move), "sequence");
+
+    let view = SpectatorView::new(&game, RevealPolicy::PublicOnly).unwrap();
+    assert_eq!(view.last_move, last_move);
+}
+
+#[test]
+pub fn a_sequence_survives_saving(){
+    let game = Game::load(get_game()).unwrap();
+    let game_def = game.player_move(0, vec!(card!(Three, Spades), card!(Four, Clubs), card!(Five, Hearts))).unwrap();
+
+    let json = ::serde_json::to_string(&game_def).unwrap();
+    let loaded: GameDefinition = ::serde_json::from_str(&json).unwrap();
+
+    assert_eq!(loaded.round.get_last_move(), game_def.round.get_last_move());
+}
+
+#[test]
+pub fn unseen_cards_have_no_jokers(){
+    let game_def = Game::setup_seeded(vec!(0, 1, 2, 3), 1, get_rules(), 7).unwrap();
+    let tracker = CardTracker::new(&game_def, 0).unwrap();
+
+    assert_eq!(tracker.unseen_jokers(), 0);
+    assert_eq!(tracker.get_unseen().len(), 39);
+}
+
+#[test]
+pub fn hints_include_sequences(){
+    let hands = vec!(
+        vec!(card!(Three, Spades), card!(Four, Clubs), card!(Five, Hearts), card!(King, Clubs)),
+        vec!(card!(Six, Spades), card!(Seven, Clubs), card!(Eight, Hearts)));
+    let game = Game::load(Game::setup_with_hands(vec!(0, 1), hands, get_rules()).unwrap()).unwrap();
+
+    let hints = game.get_hints();
+    let sequence = hints.iter().find(|h| h.cards.len() == 3).unwrap();
+
+    assert_eq!(sequence.reasons[0], "gets rid of 3 cards at once");
+    assert!(sequence.reasons.contains(&"leads with your lowest sequence".to_string()));
+}
+
+#[test]
+pub fn hints_use_tien_len_suit_order(){
+    let player1 = Player::new(0).set_hand(vec!(card!(Five, Hearts), card!(Five, Spades), card!(King, Clubs)));
+    let player2 = Player::new(1).set_hand(vec!(card!(Six, Spades)));
+    let four = Move::Single(Card::new(Rank::Four, Suit::Clubs, false));
+
+    let game_def = GameDefinition::new(vec!(player1, player2), Round::new(vec!(0, 1), 0, four, 0, false), vec!(), false)
+        .with_rules(get_rules());
+    let hints = Game::load(game_def).unwrap().get_hints();
+
+    let lowest = hints.iter().find(|h| h.reasons.iter().any(|r| r.starts_with("lowest single"))).unwrap();
+    assert_eq!(lowest.cards, vec!(card!(Five, Spades)));
+    assert_eq!(hints[0].cards, vec!(card!(Five, Spades)));
+}
@@ -0,0 +1,102 @@
+use game::game::{ Game, GameDefinition };
+use game::history::Turn;
+use game::player_move::{Move, build_move};
+use game::tracker::CardTracker;
+use cards::card::*;
+use cards::types::*;
+use game::player::Player;
+use game::round::Round;
+
+fn get_game() -> GameDefinition {
+    let game_def = Game::setup(vec!(0, 1), 1).unwrap();
+    let game = Game::load(game_def.clone()).unwrap();
+    let first = game.get_next_player().unwrap().get_id();
+
+    game.player_move(first, vec!(card!(Three, Clubs))).unwrap()
+}
+
+#[test]
+pub fn a_fresh_game_hides_every_card_outside_your_hand(){
+    let game_def = Game::setup(vec!(0, 1, 2, 3), 1).unwrap();
+
+    let tracker = CardTracker::new(&game_def, 0).unwrap();
+    let own_jokers = game_def.players[0].get_hand().iter()
+        .filter(|c| matches!(**c, PlayerCard::Joker(_)))
+        .count();
+
+    assert_eq!(tracker.get_unseen().len() + tracker.unseen_jokers(), 54 - game_def.players[0].remaining_cards());
+    assert_eq!(tracker.unseen_jokers(), 2 - own_jokers);
+}
+
+#[test]
+pub fn played_cards_are_no_longer_unseen(){
+    let game_def = get_game();
+
+    let tracker = CardTracker::new(&game_def, 0).unwrap();
+
+    assert!(!tracker.get_unseen().contains(&card!(Three, Clubs)));
+    assert_eq!(game_def.history.len(), 1);
+    assert_eq!(game_def.history[0].cards, vec!(card!(Three, Clubs)));
+}
+
+#[test]
+pub fn unknown_players_have_no_tracker(){
+    assert!(CardTracker::new(&get_game(), 9).is_err());
+}
+
+fn get_endgame(reversed: bool) -> GameDefinition {
+    // everything but the twos and a few low cards has been played
+    let mut played = vec!();
+    for suit in &[Suit::Clubs, Suit::Hearts, Suit::Diamonds, Suit::Spades] {
+        for rank in &[Rank::Six, Rank::Seven, Rank::Eight, Rank::Nine, Rank::Ten,
+                      Rank::Jack, Rank::Queen, Rank::King, Rank::Ace] {
+            played.push(PlayerCard::Card(Card::new(*rank, *suit, false)));
+        }
+    }
+    played.push(PlayerCard::Card(Card::new(Rank::Five, Suit::Clubs, false)));
+    played.push(PlayerCard::Card(Card::new(Rank::Five, Suit::Hearts, false)));
+    played.push(wildcard!(Ace, Spades));
+    played.push(wildcard!(Ace, Hearts));
+
+    let player1 = Player::new(0).set_hand(vec!(
+        card!(Two, Clubs, reversed), card!(Three, Clubs, reversed), card!(Three, Hearts, reversed)));
+    let player2 = Player::new(1).set_hand(vec!(
+        card!(Two, Hearts, reversed), card!(Two, Spades, reversed), card!(Two, Diamonds, reversed),
+        card!(Three, Spades, reversed), card!(Three, Diamonds, reversed),
+        card!(Four, Clubs, reversed), card!(Four, Hearts, reversed), card!(Four, Spades, reversed),
+        card!(Four, Diamonds, reversed), card!(Five, Spades, reversed), card!(Five, Diamonds, reversed)));
+
+    GameDefinition::new(
+        vec!(player1, player2),
+        Round::new(vec!(0, 1), 0, Move::Pass, 0, false),
+        vec!(),
+        reversed)
+        .with_history(played.into_iter().map(|c| Turn::new(1, vec!(c))).collect())
+}
+
+#[test]
+pub fn remaining_twos_can_be_counted(){
+    let tracker = CardTracker::new(&get_endgame(false), 0).unwrap();
+
+    assert_eq!(tracker.count_unseen(Rank::Two), 3);
+    assert_eq!(tracker.unseen_jokers(), 0);
+    assert_eq!(tracker.highest_unseen_single(), Some(card!(Two, Spades)));
+}
+
+#[test]
+pub fn the_highest_single_respects_reversal(){
+    let tracker = CardTracker::new(&get_endgame(true), 0).unwrap();
+
+    assert_eq!(tracker.highest_unseen_single(), Some(card!(Three, Diamonds, true)));
+}
+
+#[test]
+pub fn unseen_pairs_can_be_compared_with_a_move(){
+    let tracker = CardTracker::new(&get_endgame(false), 0).unwrap();
+
+    let pair_of_aces = build_move(vec!(card!(Ace, Clubs), card!(Ace, Hearts))).unwrap();
+    let pair_of_twos = build_move(vec!(card!(Two, Clubs), card!(Two, Spades))).unwrap();
+
+    assert!(tracker.can_unseen_beat(pair_of_aces));
+    assert!(!tracker.can_unseen_beat(pair_of_twos));
+}
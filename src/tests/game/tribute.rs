@@ -0,0 +1,131 @@
+use game::game::{Game, GameDefinition, Outcome};
+use game::rules::{Rules, Variant};
+use game::tribute::*;
+use cards::card::*;
+use cards::types::*;
+
+fn get_rules(tribute: Vec<usize>) -> Rules {
+    Rules{ tribute, ..Rules::default() }
+}
+
+fn get_game(rules: Rules) -> GameDefinition {
+    let hands = vec!(
+        vec!(card!(Three, Clubs), card!(Four, Clubs), card!(Five, Clubs)),
+        vec!(card!(Six, Clubs), card!(Seven, Clubs), card!(Eight, Clubs)),
+        vec!(card!(Nine, Clubs), card!(Ten, Clubs), card!(Jack, Clubs)),
+        vec!(card!(Queen, Clubs), card!(Two, Hearts), PlayerCard::Joker(1)));
+
+    Game::setup_with_hands(vec!(1, 2, 3, 4), hands, rules).unwrap()
+}
+
+fn get_previous() -> Outcome {
+    Outcome{ finishing_order: vec!(2, 3, 1, 4) }
+}
+
+fn get_hand(game: &GameDefinition, id: u64) -> Vec<PlayerCard> {
+    game.players.iter().find(|p| p.get_id() == id).unwrap().get_hand()
+}
+
+#[test]
+pub fn the_loser_gives_their_best_cards_to_the_winner(){
+    let exchange = Exchange::new(get_game(get_rules(vec!(2, 1))), &get_previous()).unwrap();
+    let tributes = exchange.get_tributes();
+
+    assert_eq!(tributes.len(), 2);
+    assert_eq!((tributes[0].from, tributes[0].to), (4, 2));
+    assert_eq!(tributes[0].given, vec!(PlayerCard::Joker(1), card!(Two, Hearts)));
+    assert_eq!((tributes[1].from, tributes[1].to), (1, 3));
+    assert_eq!(tributes[1].given, vec!(card!(Five, Clubs)));
+
+    let game = exchange.get_game();
+    assert_eq!(get_hand(&game, 4), vec!(card!(Queen, Clubs)));
+    assert_eq!(get_hand(&game, 2).len(), 5);
+}
+
+#[test]
+pub fn tien_len_tributes_use_its_suit_order(){
+    let hands = vec!(vec!(card!(Three, Spades), card!(Ace, Hearts), card!(Ace, Spades)), vec!(card!(Four, Clubs)));
+    let rules = Rules{ variant: Variant::TienLen, ..get_rules(vec!(1)) };
+    let game = Game::setup_with_hands(vec!(1, 2), hands, rules).unwrap();
+
+    let exchange = Exchange::new(game, &Outcome{ finishing_order: vec!(2, 1) }).unwrap();
+
+    assert_eq!(exchange.get_tributes()[0].given, vec!(card!(Ace, Hearts)));
+}
+
+#[test]
+pub fn the_winner_returns_cards_of_their_choice(){
+    let exchange = Exchange::new(get_game(get_rules(vec!(2, 1))), &get_previous()).unwrap();
+
+    let exchange = exchange.return_cards(2, vec!(card!(Six, Clubs), card!(Seven, Clubs))).unwrap();
+    assert!(!exchange.is_complete());
+
+    let exchange = exchange.return_cards(3, vec!(card!(Nine, Clubs))).unwrap();
+    assert!(exchange.is_complete());
+
+    let game = exchange.get_game();
+    assert_eq!(get_hand(&game, 4), vec!(card!(Queen, Clubs), card!(Six, Clubs), card!(Seven, Clubs)));
+    assert_eq!(get_hand(&game, 1), vec!(card!(Three, Clubs), card!(Four, Clubs), card!(Nine, Clubs)));
+}
+
+#[test]
+pub fn returned_cards_are_checked(){
+    let exchange = Exchange::new(get_game(get_rules(vec!(2, 1))), &get_previous()).unwrap();
+
+    assert_eq!(exchange.return_cards(2, vec!(card!(Six, Clubs))).err(), Some("Return as many cards as were given"));
+    assert_eq!(exchange.return_cards(2, vec!(card!(Six, Clubs), card!(Three, Clubs))).err(), Some("Cannot return cards you do not have"));
+    assert_eq!(exchange.return_cards(4, vec!(card!(Queen, Clubs))).err(), Some("No cards are owed by this player"));
+
+    let exchange = exchange.return_cards(3, vec!(card!(Nine, Clubs))).unwrap();
+    assert_eq!(exchange.return_cards(3, vec!(card!(Ten, Clubs))).err(), Some("No cards are owed by this player"));
+}
+
+#[test]
+pub fn play_only_starts_once_every_tribute_is_returned(){
+    let exchange = Exchange::new(get_game(get_rules(vec!(1))), &get_previous()).unwrap();
+
+    assert_eq!(exchange.start().err(), Some("Every tribute must be returned before play starts"));
+
+    // the winner can send back the card they were given
+    let exchange = exchange.return_cards(2, vec!(PlayerCard::Joker(1))).unwrap();
+    let game = exchange.start().unwrap();
+
+    assert_eq!(game.setup.tributes, exchange.get_tributes());
+    assert_eq!(get_hand(&game, 4), vec!(card!(Queen, Clubs), card!(Two, Hearts), PlayerCard::Joker(1)));
+    assert_eq!(get_hand(&game, 2), vec!(card!(Six, Clubs), card!(Seven, Clubs), card!(Eight, Clubs)));
+}
+
+#[test]
+pub fn the_lead_follows_the_three_of_clubs(){
+    let exchange = Exchange::new(get_game(get_rules(vec!(1, 1))), &get_previous()).unwrap();
+    let exchange = exchange.return_cards(2, vec!(card!(Six, Clubs))).unwrap();
+    let exchange = exchange.return_cards(3, vec!(card!(Five, Clubs))).unwrap();
+
+    // player 1 gave up the 5 and got it straight back
+    let game = Game::load(exchange.start().unwrap()).unwrap();
+    assert_eq!(game.get_next_player().unwrap().get_id(), 1);
+}
+
+#[test]
+pub fn no_tributes_by_default(){
+    let exchange = Exchange::new(get_game(Rules::default()), &get_previous()).unwrap();
+
+    assert!(exchange.get_tributes().is_empty());
+    assert!(exchange.start().is_ok());
+}
+
+#[test]
+pub fn tributes_need_matching_players(){
+    let previous = Outcome{ finishing_order: vec!(2, 3, 1, 5) };
+    assert!(Exchange::new(get_game(get_rules(vec!(1))), &previous).is_err());
+
+    assert!(Exchange::new(get_game(get_rules(vec!(1, 1, 1))), &get_previous()).is_err());
+}
+
+#[test]
+pub fn there_are_no_tributes_once_play_has_started(){
+    let game = Game::load(get_game(get_rules(vec!(1)))).unwrap();
+    let game = game.player_move(1, vec!(card!(Three, Clubs))).unwrap();
+
+    assert!(Exchange::new(game, &get_previous()).is_err());
+}
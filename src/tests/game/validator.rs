@@ -0,0 +1,106 @@
+use game::game::{ Game, GameDefinition };
+use game::rules::{ Rules, Variant };
+use game::validator::Violation;
+use game::player_move::Move;
+use cards::card::*;
+use cards::types::*;
+use game::round::Round;
+
+fn get_game() -> GameDefinition {
+    let game_def = Game::setup(vec!(0, 1, 2, 3), 1).unwrap();
+    let game = Game::load(game_def.clone()).unwrap();
+    let first = game.get_next_player().unwrap().get_id();
+
+    game.player_move(first, vec!(card!(Three, Clubs))).unwrap()
+}
+
+#[test]
+pub fn a_fresh_game_is_valid(){
+    let game_def = Game::setup(vec!(0, 1, 2, 3), 2).unwrap();
+
+    assert_eq!(game_def.validate(), vec!());
+}
+
+#[test]
+pub fn a_fresh_tien_len_game_is_valid(){
+    let rules = Rules{ variant: Variant::TienLen, ..Rules::default() };
+
+    assert_eq!(Game::setup_with_rules(vec!(0, 1, 2, 3), 1, rules.clone()).unwrap().validate(), vec!());
+    assert_eq!(Game::setup_with_rules(vec!(0, 1, 2, 3), 2, rules).unwrap().validate(), vec!());
+}
+
+#[test]
+pub fn a_game_in_progress_is_valid(){
+    assert_eq!(get_game().validate(), vec!());
+}
+
+#[test]
+pub fn duplicated_cards_are_found(){
+    let mut game_def = get_game();
+    let hand = game_def.players[1].get_hand();
+    let mut duplicated = hand.clone();
+    duplicated.push(card!(Three, Clubs));
+    game_def.players[1] = game_def.players[1].set_hand(duplicated);
+
+    let violations = game_def.validate();
+
+    assert!(violations.contains(&Violation::DuplicateCard(Card::new(Rank::Three, Suit::Clubs, false))));
+    assert!(violations.contains(&Violation::CardCount{ expected: 54, found: 55 }));
+}
+
+#[test]
+pub fn missing_cards_are_found(){
+    let mut game_def = get_game();
+    let mut hand = game_def.players[2].get_hand();
+    hand.pop();
+    game_def.players[2] = game_def.players[2].set_hand(hand);
+
+    assert_eq!(game_def.validate(), vec!(Violation::CardCount{ expected: 54, found: 53 }));
+}
+
+#[test]
+pub fn winners_must_have_finished(){
+    let mut game_def = get_game();
+    game_def.winners = vec!(2, 2);
+
+    let violations = game_def.validate();
+
+    assert!(violations.contains(&Violation::WinnerHasCards(2)));
+    assert!(violations.contains(&Violation::DuplicateWinner(2)));
+}
+
+#[test]
+pub fn finished_players_cannot_be_in_the_round(){
+    let mut game_def = get_game();
+    let current = game_def.round.get_next_player();
+    let index = game_def.players.iter().position(|p| p.get_id() == current).unwrap();
+    let hand = game_def.players[index].get_hand();
+    game_def.players[index] = game_def.players[index].set_hand(vec!());
+    let other = (index + 1) % 4;
+    let mut other_hand = game_def.players[other].get_hand();
+    other_hand.extend(hand);
+    game_def.players[other] = game_def.players[other].set_hand(other_hand);
+
+    let violations = game_def.validate();
+
+    assert!(violations.contains(&Violation::FinishedPlayerInRound(current)));
+    assert!(violations.contains(&Violation::FinishedPlayerNotWinner(current)));
+}
+
+#[test]
+pub fn the_last_move_must_match_the_reversed_flag(){
+    let mut game_def = get_game();
+    let last_move = Move::Single(Card::new(Rank::Three, Suit::Clubs, true));
+    game_def.round = Round::new(vec!(0, 1, 2, 3), game_def.round.get_next_player(), last_move, 0, false);
+
+    assert_eq!(game_def.validate(), vec!(Violation::LastMoveNotOriented));
+}
+
+#[test]
+pub fn load_validated_refuses_an_invalid_game(){
+    let mut game_def = get_game();
+    game_def.winners = vec!(9);
+
+    assert_eq!(Game::load_validated(game_def).err(), Some(vec!(Violation::UnknownPlayer(9))));
+    assert!(Game::load_validated(get_game()).is_ok());
+}
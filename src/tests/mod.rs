@@ -1,3 +1,9 @@
 mod cards;
 mod game;
-
+mod tournament;
+#[cfg(feature = "svg")]
+mod svg;
+#[cfg(feature = "ffi")]
+mod ffi;
+#[cfg(feature = "wasm")]
+mod wasm;
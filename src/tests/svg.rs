@@ -0,0 +1,72 @@
+use cards::card::*;
+use cards::types::*;
+use game::game::Game;
+use game::player_move::{Move, build_move};
+use game::tien_len::TienLenMove;
+use svg::*;
+
+fn count(svg: &str, pattern: &str) -> usize {
+    svg.matches(pattern).count()
+}
+
+#[test]
+pub fn a_hand_is_drawn_card_by_card(){
+    let hand = vec!(card!(Three, Clubs), card!(Ten, Hearts), wildcard!(Ace, Spades), PlayerCard::Joker(0));
+
+    let svg = render_hand(&hand);
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.ends_with("</svg>"));
+    assert_eq!(count(&svg, "<rect width=\"60\""), 4);
+    assert_eq!(count(&svg, "stroke-dasharray"), 1);
+    assert!(svg.contains(">10</text>"));
+    assert!(svg.contains(">JOKER</text>"));
+}
+
+#[test]
+pub fn red_suits_are_drawn_in_red(){
+    let svg = render_hand(&vec!(card!(Four, Diamonds)));
+
+    assert!(svg.contains("fill=\"#c00000\""));
+    assert!(!svg.contains("fill=\"#000000\""));
+}
+
+#[test]
+pub fn a_move_is_drawn(){
+    let pair = build_move(vec!(card!(Six, Clubs), card!(Six, Hearts))).unwrap();
+
+    assert_eq!(count(&render_move(pair), "<rect width=\"60\""), 2);
+    assert!(render_move(Move::Pass).contains(">Pass</text>"));
+}
+
+#[test]
+pub fn a_tien_len_sequence_is_drawn_whole(){
+    let sequence = TienLenMove::build(&[card!(Three, Spades), card!(Four, Clubs), card!(Five, Hearts), card!(Six, Hearts)]).unwrap();
+
+    assert_eq!(count(&render_move(sequence.to_move()), "<rect width=\"60\""), 4);
+}
+
+#[test]
+pub fn a_table_shows_every_seat(){
+    let game_def = Game::setup(vec!(4, 5, 6, 7), 1).unwrap();
+    let first = Game::load(game_def.clone()).unwrap().get_next_player().unwrap().get_id();
+    let game_def = Game::load(game_def).unwrap().player_move(first, vec!(card!(Three, Clubs))).unwrap();
+
+    let svg = render_table(&game_def).unwrap();
+
+    for player in &game_def.players {
+        assert!(svg.contains(&format!(">Player {}</text>", player.get_id())));
+        assert!(svg.contains(&format!(">{} cards</text>", player.remaining_cards())));
+    }
+    assert!(svg.contains(">To play</text>"));
+    assert_eq!(count(&svg, "<rect width=\"60\""), 1);
+    assert!(!svg.contains("Reversed"));
+}
+
+#[test]
+pub fn a_reversed_table_is_marked(){
+    let mut game_def = Game::setup(vec!(0, 1, 2, 3), 1).unwrap();
+    game_def.reversed = true;
+
+    assert!(render_table(&game_def).unwrap().contains(">Reversed</text>"));
+}
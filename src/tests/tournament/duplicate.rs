@@ -0,0 +1,95 @@
+use game::game::{ Game, Outcome };
+use game::rules::{Rules, Variant};
+use tournament::duplicate::*;
+
+#[test]
+pub fn a_duplicate_deal_reproduces_a_seeded_setup(){
+    let deal = DuplicateDeal::new(42, 1, 4);
+
+    let seeded = Game::setup_seeded(vec!(0, 1, 2, 3), 1, Rules::default(), 42).unwrap();
+    let duplicate = deal.setup(vec!(0, 1, 2, 3), &[0, 1, 2, 3], Rules::default()).unwrap();
+
+    assert_eq!(seeded.players, duplicate.players);
+    assert_eq!(seeded.round, duplicate.round);
+}
+
+#[test]
+pub fn a_tien_len_duplicate_deal_reproduces_a_seeded_setup(){
+    let deal = DuplicateDeal::new(42, 1, 4);
+    let rules = Rules{ variant: Variant::TienLen, ..Rules::default() };
+
+    let seeded = Game::setup_seeded(vec!(0, 1, 2, 3), 1, rules.clone(), 42).unwrap();
+    let duplicate = deal.setup(vec!(0, 1, 2, 3), &[0, 1, 2, 3], rules).unwrap();
+
+    assert_eq!(seeded.players, duplicate.players);
+    assert_eq!(seeded.round, duplicate.round);
+    assert!(deal.get_hands(Variant::TienLen).iter().all(|hand| hand.len() == 13));
+}
+
+#[test]
+pub fn seats_can_be_permuted(){
+    let deal = DuplicateDeal::new(42, 1, 4);
+    let hands = deal.get_hands(Variant::PusoyDos);
+
+    let game_def = deal.setup(vec!(10, 11, 12, 13), &[2, 3, 0, 1], Rules::default()).unwrap();
+
+    assert_eq!(game_def.players[0].get_hand(), hands[2]);
+    assert_eq!(game_def.players[3].get_hand(), hands[1]);
+    assert!(Game::load(game_def).is_ok());
+}
+
+#[test]
+pub fn permutations_must_use_every_hand_once(){
+    let deal = DuplicateDeal::new(42, 1, 4);
+
+    assert!(deal.setup(vec!(0, 1, 2, 3), &[0, 0, 1, 2], Rules::default()).is_err());
+    assert!(deal.setup(vec!(0, 1, 2), &[0, 1, 2], Rules::default()).is_err());
+}
+
+#[test]
+pub fn rotations_give_every_seat_every_hand(){
+    let rotations = DuplicateDeal::new(1, 1, 4).get_rotations();
+
+    assert_eq!(rotations.len(), 4);
+    for seat in 0..4 {
+        let mut hands: Vec<usize> = rotations.iter().map(|r| r[seat]).collect();
+        hands.sort();
+        assert_eq!(hands, vec!(0, 1, 2, 3));
+    }
+}
+
+#[test]
+pub fn players_are_scored_against_others_with_the_same_cards(){
+    // each hand wins once, so each winner gains half a place on the average
+    let tables = vec!(
+        DuplicateTable{
+            players: vec!(0, 1),
+            permutation: vec!(0, 1),
+            outcome: Outcome{ finishing_order: vec!(0, 1) }
+        },
+        DuplicateTable{
+            players: vec!(2, 3),
+            permutation: vec!(1, 0),
+            outcome: Outcome{ finishing_order: vec!(2, 3) }
+        });
+
+    let scores = compare(&tables).unwrap();
+
+    assert_eq!(scores[0].player, 0);
+    assert_eq!(scores[0].score, 0.5);
+    assert_eq!(scores[1].player, 2);
+    assert_eq!(scores[1].score, 0.5);
+    assert_eq!(scores[3].score, -0.5);
+    assert!(scores.iter().all(|s| s.games == 1));
+}
+
+#[test]
+pub fn mismatched_results_are_rejected(){
+    let tables = vec!(DuplicateTable{
+        players: vec!(0, 1),
+        permutation: vec!(0, 1),
+        outcome: Outcome{ finishing_order: vec!(0, 5) }
+    });
+
+    assert!(compare(&tables).is_err());
+}
@@ -0,0 +1,3 @@
+pub mod tournament;
+pub mod duplicate;
+pub mod rating;
@@ -0,0 +1,78 @@
+use game::game::Outcome;
+use tournament::rating::*;
+
+fn outcome(order: Vec<u64>) -> Outcome {
+    Outcome{ finishing_order: order }
+}
+
+#[test]
+pub fn unrated_players_start_with_the_default(){
+    let store = RatingStore::new();
+
+    assert_eq!(store.get_rating(3), Rating::default());
+}
+
+#[test]
+pub fn finishing_order_moves_ratings(){
+    let mut store = RatingStore::new();
+
+    let deltas = store.record(&outcome(vec!(2, 0, 1, 3))).unwrap();
+
+    assert_eq!(deltas.len(), 4);
+    assert!(deltas[0].change() > 0.0);
+    assert!(deltas[0].change() > deltas[1].change());
+    assert!(deltas[1].change() > 0.0);
+    assert!(deltas[2].change() < 0.0);
+    assert!(deltas[3].change() < deltas[2].change());
+
+    let total: f64 = deltas.iter().map(|d| d.change()).sum();
+    assert!(total.abs() < 1e-9);
+
+    let ratings = store.get_ratings();
+    assert_eq!(ratings.iter().map(|r| r.0).collect::<Vec<u64>>(), vec!(2, 0, 1, 3));
+}
+
+#[test]
+pub fn two_player_games_are_rated(){
+    let mut store = RatingStore::new();
+
+    let deltas = store.record(&outcome(vec!(5, 6))).unwrap();
+
+    assert_eq!(deltas[0].change(), -deltas[1].change());
+}
+
+#[test]
+pub fn uncertainty_shrinks_with_games_played(){
+    let mut store = RatingStore::new();
+
+    let first = store.record(&outcome(vec!(0, 1))).unwrap()[0].change();
+    store.record(&outcome(vec!(1, 0))).unwrap();
+    let third = store.record(&outcome(vec!(0, 1))).unwrap()[0].change();
+
+    let rating = store.get_rating(0);
+    assert_eq!(rating.games, 3);
+    assert!(rating.deviation < Rating::default().deviation);
+    assert!(third < first);
+}
+
+#[test]
+pub fn beating_a_stronger_player_is_worth_more(){
+    let mut store = RatingStore::new();
+    for _ in 0..5 {
+        store.record(&outcome(vec!(0, 1, 2))).unwrap();
+    }
+
+    let upset = store.record(&outcome(vec!(2, 0))).unwrap()[0].change();
+    let expected = store.record(&outcome(vec!(0, 1))).unwrap()[0].change();
+
+    assert!(upset > expected);
+}
+
+#[test]
+pub fn invalid_outcomes_are_rejected(){
+    let mut store = RatingStore::new();
+
+    assert!(store.record(&outcome(vec!(1))).is_err());
+    assert!(store.record(&outcome(vec!(1, 2, 1))).is_err());
+    assert_eq!(store.get_ratings().len(), 0);
+}
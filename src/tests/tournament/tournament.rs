@@ -0,0 +1,127 @@
+use game::game::{ Game, Outcome };
+use game::rules::Rules;
+use tournament::tournament::*;
+
+fn play_round(tournament: &mut Tournament) {
+    let tables = tournament.start_round().unwrap();
+
+    // lowest id wins at every table
+    for (i, table) in tables.iter().enumerate() {
+        let mut order = table.players.clone();
+        order.sort();
+        tournament.record_result(i, &Outcome{ finishing_order: order }).unwrap();
+    }
+}
+
+#[test]
+pub fn a_tournament_needs_a_full_table(){
+    assert!(Tournament::new(vec!(0, 1, 2), Format::Swiss(3), 1, Rules::default()).is_err());
+    assert!(Tournament::new(vec!(0, 1, 2, 2), Format::Swiss(3), 1, Rules::default()).is_err());
+}
+
+#[test]
+pub fn players_are_seated_at_tables_of_four(){
+    let mut tournament = Tournament::new((0..8).collect(), Format::Swiss(3), 1, Rules::default()).unwrap();
+
+    let tables = tournament.start_round().unwrap();
+
+    assert_eq!(tables.len(), 2);
+    assert!(tables.iter().all(|t| t.players.len() == 4));
+    assert_eq!(tournament.get_byes(), Vec::<u64>::new());
+}
+
+#[test]
+pub fn tables_in_a_round_play_the_same_seeded_deal(){
+    let mut tournament = Tournament::new((0..8).collect(), Format::Swiss(3), 1, Rules::default()).unwrap();
+    tournament.start_round().unwrap();
+
+    let first = tournament.setup_table(0).unwrap();
+    let second = tournament.setup_table(1).unwrap();
+
+    let hands = |def: &::game::game::GameDefinition| def.players.iter().map(|p| p.get_hand()).collect::<Vec<_>>();
+    assert_eq!(hands(&first), hands(&second));
+    assert!(Game::load(first).is_ok());
+}
+
+#[test]
+pub fn extra_players_are_seated_at_tables_of_three(){
+    let sizes = |players: u64| {
+        let mut tournament = Tournament::new((0..players).collect(), Format::Swiss(1), 1, Rules::default()).unwrap();
+        let tables = tournament.start_round().unwrap();
+
+        assert_eq!(tournament.get_byes(), Vec::<u64>::new());
+        assert!(tournament.setup_table(tables.len() - 1).is_ok());
+        tables.iter().map(|t| t.players.len()).collect::<Vec<_>>()
+    };
+
+    assert_eq!(sizes(6), vec!(3, 3));
+    assert_eq!(sizes(7), vec!(4, 3));
+    assert_eq!(sizes(9), vec!(3, 3, 3));
+    assert_eq!(sizes(10), vec!(4, 3, 3));
+    assert_eq!(sizes(11), vec!(4, 4, 3));
+}
+
+#[test]
+pub fn a_fifth_player_gets_a_bye(){
+    let mut tournament = Tournament::new((0..5).collect(), Format::Swiss(2), 1, Rules::default()).unwrap();
+
+    play_round(&mut tournament);
+    let first_byes = tournament.get_byes();
+    assert_eq!(first_byes.len(), 1);
+
+    play_round(&mut tournament);
+    let second_byes = tournament.get_byes();
+
+    // nobody sits out twice while others have not
+    assert!(first_byes.iter().all(|p| !second_byes.contains(p)));
+    assert!(tournament.get_standings().iter().all(|s| s.games + s.byes == 2));
+}
+
+#[test]
+pub fn swiss_rounds_seat_players_by_standings(){
+    let mut tournament = Tournament::new((0..8).collect(), Format::Swiss(3), 7, Rules::default()).unwrap();
+
+    play_round(&mut tournament);
+    let leaders: Vec<u64> = tournament.get_standings().iter().take(4).map(|s| s.player).collect();
+
+    let tables = tournament.start_round().unwrap();
+    let mut top_table = tables[0].players.clone();
+    top_table.sort();
+    let mut expected = leaders.clone();
+    expected.sort();
+
+    assert_eq!(top_table, expected);
+}
+
+#[test]
+pub fn results_must_match_the_table(){
+    let mut tournament = Tournament::new((0..4).collect(), Format::Swiss(1), 1, Rules::default()).unwrap();
+    tournament.start_round().unwrap();
+
+    assert!(tournament.record_result(0, &Outcome{ finishing_order: vec!(0, 1, 2, 9) }).is_err());
+    assert!(tournament.start_round().is_err());
+
+    tournament.record_result(0, &Outcome{ finishing_order: vec!(3, 2, 1, 0) }).unwrap();
+    assert!(tournament.record_result(0, &Outcome{ finishing_order: vec!(3, 2, 1, 0) }).is_err());
+
+    assert!(tournament.is_finished());
+    assert_eq!(tournament.get_standings()[0].player, 3);
+    assert_eq!(tournament.get_standings()[0].points, 3);
+}
+
+#[test]
+pub fn knockouts_run_until_one_player_remains(){
+    let mut tournament = Tournament::new((0..8).collect(), Format::Knockout, 3, Rules::default()).unwrap();
+
+    play_round(&mut tournament);
+    assert_eq!(tournament.get_standings().iter().filter(|s| !s.eliminated).count(), 4);
+    assert!(!tournament.is_finished());
+
+    play_round(&mut tournament);
+    assert!(tournament.is_finished());
+
+    let standings = tournament.get_standings();
+    assert_eq!(standings[0].player, 0);
+    assert_eq!(standings.iter().filter(|s| !s.eliminated).count(), 1);
+    assert!(tournament.start_round().is_err());
+}
@@ -0,0 +1,44 @@
+use wasm;
+use game::game::{ Game, GameDefinition };
+use game::rules::Rules;
+use cards::card::*;
+use cards::types::*;
+
+fn get_state() -> String {
+    let rules = Rules{ announce_last_card: true, ..Rules::default() };
+    let hands = vec!(
+        vec!(card!(Three, Clubs), card!(Five, Clubs)),
+        vec!(card!(Four, Clubs), card!(Six, Clubs), card!(Seven, Clubs)));
+
+    ::serde_json::to_string(&Game::setup_with_hands(vec!(0, 1), hands, rules).unwrap()).unwrap()
+}
+
+#[test]
+pub fn a_last_card_can_be_announced(){
+    let cards = ::serde_json::to_string(&vec!(card!(Three, Clubs))).unwrap();
+
+    let next = wasm::player_move_announcing(&get_state(), 0, &cards, true).unwrap();
+    let game_def: GameDefinition = ::serde_json::from_str(&next).unwrap();
+
+    assert!(game_def.history[0].last_card);
+}
+
+#[test]
+pub fn numbered_jokers_can_be_parsed(){
+    let parsed = wasm::parse_cards("3C JK JK1 *2S").unwrap();
+
+    let expected = vec!(card!(Three, Clubs), PlayerCard::Joker(0), PlayerCard::Joker(1), wildcard!(Two, Spades));
+    assert_eq!(parsed, ::serde_json::to_string(&expected).unwrap());
+}
+
+#[test]
+pub fn parsed_jokers_match_a_dealt_hand(){
+    let hands = vec!(
+        vec!(card!(Three, Clubs), PlayerCard::Joker(0), PlayerCard::Joker(1)),
+        vec!(card!(Four, Clubs), card!(Six, Clubs)));
+    let game_def = Game::setup_with_hands(vec!(0, 1), hands, Rules::default()).unwrap();
+
+    let parsed: Vec<PlayerCard> = ::serde_json::from_str(&wasm::parse_cards("3C JK JK1").unwrap()).unwrap();
+
+    assert_eq!(parsed, game_def.players[0].get_hand());
+}
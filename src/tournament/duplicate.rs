@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use cards::card::PlayerCard;
+use cards::deck::Deck;
+use game::game::{Game, GameDefinition, Outcome};
+use game::rules::{Rules, Variant};
+
+/// a seeded deal that can be replayed at several tables
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateDeal {
+    /// seed for the shuffle
+    pub seed: u64,
+    /// decks combined for the deal
+    pub num_decks: usize,
+    /// number of hands dealt
+    pub seats: usize
+}
+
+/// a game played from a `DuplicateDeal`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateTable {
+    /// players in seat order
+    pub players: Vec<u64>,
+    /// the hand played from each seat
+    pub permutation: Vec<usize>,
+    /// how the game finished
+    pub outcome: Outcome
+}
+
+/// how a player did compared with everyone else who held the same cards
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateScore {
+    /// player id
+    pub player: u64,
+    /// places gained over the average for the hands played -
+    /// positive is better than average
+    pub score: f64,
+    /// games played
+    pub games: usize
+}
+
+impl DuplicateDeal {
+
+    /// create a new deal
+    pub fn new(seed: u64, num_decks: usize, seats: usize) -> DuplicateDeal {
+        DuplicateDeal { seed, num_decks, seats }
+    }
+
+    /// the hands in the order `Game::setup_seeded` deals them
+    /// for a variant
+    pub fn get_hands(&self, variant: Variant) -> Vec<Vec<PlayerCard>> {
+        let decks = (0..self.num_decks).map(|_| Deck::new()).collect();
+        let mut deck = Deck::combine(decks);
+        deck.shuffle_seeded(self.seed);
+        variant.deal(&deck, self.seats)
+    }
+
+    /// deal to a table, giving seat `i` the hand `permutation[i]`
+    pub fn setup(&self, players: Vec<u64>, permutation: &[usize], rules: Rules) -> Result<GameDefinition, &'static str> {
+        if players.len() != self.seats || !is_permutation(permutation, self.seats) {
+            return Err("Every seat needs a different hand");
+        }
+
+        let hands = self.get_hands(rules.variant);
+        let dealt = permutation.iter().map(|&h| hands[h].clone()).collect();
+
+        Game::setup_with_hands(players, dealt, rules)
+    }
+
+    /// seatings that give each hand to a different seat at each table
+    pub fn get_rotations(&self) -> Vec<Vec<usize>> {
+        (0..self.seats).map(|table| {
+            (0..self.seats).map(|seat| (seat + table) % self.seats).collect()
+        }).collect()
+    }
+}
+
+/// score every player against others who played the same hands
+pub fn compare(tables: &[DuplicateTable]) -> Result<Vec<DuplicateScore>, &'static str> {
+    // (player, hand, place) at each table, where place 0 is first
+    let mut places: Vec<Vec<(u64, usize, usize)>> = vec!();
+
+    for table in tables {
+        if !is_permutation(&table.permutation, table.players.len()) || table.players.len() != table.outcome.finishing_order.len() {
+            return Err("Table result does not match its seating");
+        }
+
+        let mut placed = vec!();
+        for (seat, player) in table.players.iter().enumerate() {
+            let place = table.outcome.finishing_order.iter().position(|p| p == player)
+                .ok_or("Table result does not match its seating")?;
+            placed.push((*player, table.permutation[seat], place));
+        }
+        places.push(placed);
+    }
+
+    let mut totals: HashMap<usize, (usize, usize)> = HashMap::new();
+    for &(_, hand, place) in places.iter().flat_map(|p| p.iter()) {
+        let total = totals.entry(hand).or_insert((0, 0));
+        total.0 += place;
+        total.1 += 1;
+    }
+
+    let mut scores: Vec<DuplicateScore> = vec!();
+    for &(player, hand, place) in places.iter().flat_map(|p| p.iter()) {
+        let (sum, count) = totals[&hand];
+        let average = sum as f64 / count as f64;
+
+        match scores.iter_mut().find(|s| s.player == player) {
+            Some(score) => {
+                score.score += average - place as f64;
+                score.games += 1;
+            },
+            None => scores.push(DuplicateScore { player, score: average - place as f64, games: 1 })
+        }
+    }
+
+    scores.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap().then(a.player.cmp(&b.player)));
+    Ok(scores)
+}
+
+fn is_permutation(permutation: &[usize], size: usize) -> bool {
+    let mut sorted = permutation.to_vec();
+    sorted.sort();
+    sorted == (0..size).collect::<Vec<usize>>()
+}
@@ -0,0 +1,6 @@
+///! seating, pairing and standings for many games
+pub mod tournament;
+///! the same deal played at several tables
+pub mod duplicate;
+///! player ratings from finishing orders
+pub mod rating;
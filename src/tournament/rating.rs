@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+
+use game::game::Outcome;
+
+// a new player's rating and uncertainty
+const INITIAL_RATING: f64 = 1500.0;
+const INITIAL_DEVIATION: f64 = 350.0;
+// uncertainty never drops below this, so ratings keep moving
+const MIN_DEVIATION: f64 = 50.0;
+// the most a rating can move against one opponent, for a new player
+const MAX_K: f64 = 64.0;
+
+/// a player's skill estimate
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Rating {
+    /// estimated skill
+    pub rating: f64,
+    /// uncertainty in the estimate, shrinking as games are played
+    pub deviation: f64,
+    /// rated games played
+    pub games: usize
+}
+
+impl Default for Rating {
+    fn default() -> Rating {
+        Rating {
+            rating: INITIAL_RATING,
+            deviation: INITIAL_DEVIATION,
+            games: 0
+        }
+    }
+}
+
+/// how a game changed a player's rating
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RatingDelta {
+    /// player id
+    pub player: u64,
+    /// rating before the game
+    pub before: f64,
+    /// rating after the game
+    pub after: f64
+}
+
+impl RatingDelta {
+    /// the change in rating
+    pub fn change(&self) -> f64 {
+        self.after - self.before
+    }
+}
+
+/// ratings for every player, keyed by id
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RatingStore {
+    ratings: BTreeMap<u64, Rating>
+}
+
+impl RatingStore {
+
+    /// create an empty store
+    pub fn new() -> RatingStore {
+        RatingStore::default()
+    }
+
+    /// a player's rating - players without games get the default
+    pub fn get_rating(&self, player: u64) -> Rating {
+        self.ratings.get(&player).cloned().unwrap_or_default()
+    }
+
+    /// every rated player, best first
+    pub fn get_ratings(&self) -> Vec<(u64, Rating)> {
+        let mut ratings: Vec<(u64, Rating)> = self.ratings.iter().map(|(p, r)| (*p, *r)).collect();
+        ratings.sort_by(|a, b| b.1.rating.partial_cmp(&a.1.rating).unwrap().then(a.0.cmp(&b.0)));
+        ratings
+    }
+
+    /// update ratings from a finished game
+    ///
+    /// every player is treated as having beaten each player who finished
+    /// below them, and the rating change is averaged over opponents
+    pub fn record(&mut self, outcome: &Outcome) -> Result<Vec<RatingDelta>, &'static str> {
+        let order = &outcome.finishing_order;
+
+        if order.len() < 2 {
+            return Err("A rated game needs at least two players");
+        }
+
+        let mut ids = order.clone();
+        ids.sort();
+        ids.dedup();
+        if ids.len() != order.len() {
+            return Err("Players can only finish once");
+        }
+
+        let before: Vec<Rating> = order.iter().map(|p| self.get_rating(*p)).collect();
+        let opponents = (order.len() - 1) as f64;
+
+        let deltas = order.iter().enumerate().map(|(place, player)| {
+            let own = before[place];
+
+            let surprise: f64 = before.iter().enumerate()
+                .filter(|&(other, _)| other != place)
+                .map(|(other, r)| {
+                    let actual = if place < other { 1.0 } else { 0.0 };
+                    actual - expected_score(own.rating, r.rating)
+                })
+                .sum();
+
+            let k = MAX_K * own.deviation / INITIAL_DEVIATION;
+            let games = own.games + 1;
+
+            self.ratings.insert(*player, Rating {
+                rating: own.rating + k * surprise / opponents,
+                deviation: (INITIAL_DEVIATION / (games as f64 + 1.0).sqrt()).max(MIN_DEVIATION),
+                games
+            });
+
+            RatingDelta {
+                player: *player,
+                before: own.rating,
+                after: self.ratings[player].rating
+            }
+        }).collect();
+
+        Ok(deltas)
+    }
+}
+
+// chance of finishing above an opponent
+fn expected_score(rating: f64, opponent: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((opponent - rating) / 400.0))
+}
@@ -0,0 +1,267 @@
+use rand::{Rng, SeedableRng, StdRng};
+
+use game::game::{Game, GameDefinition, Outcome};
+use game::rules::Rules;
+
+// players seated at a full table
+const TABLE_SIZE: usize = 4;
+
+/// how players move between rounds
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Format {
+    /// a fixed number of rounds, each seating players alongside
+    /// others with similar standings
+    Swiss(usize),
+    /// the top half of each table goes through until one
+    /// player remains
+    Knockout
+}
+
+/// a table in the current round
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Table {
+    /// players in seat order
+    pub players: Vec<u64>,
+    /// seed for the deal - every table in a round shares it
+    pub seed: u64,
+    /// finishing order, once the game has been played
+    pub result: Option<Vec<u64>>
+}
+
+/// a player's position in the tournament
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Standing {
+    /// player id
+    pub player: u64,
+    /// points from placings and byes
+    pub points: u64,
+    /// games won
+    pub wins: usize,
+    /// games played
+    pub games: usize,
+    /// rounds sat out
+    pub byes: usize,
+    /// knocked out
+    pub eliminated: bool
+}
+
+/// a tournament of many games
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Tournament {
+    format: Format,
+    seed: u64,
+    rules: Rules,
+    round: usize,
+    tables: Vec<Table>,
+    byes: Vec<u64>,
+    standings: Vec<Standing>
+}
+
+impl Tournament {
+
+    /// create a new tournament, which needs at least one full table
+    pub fn new(players: Vec<u64>, format: Format, seed: u64, rules: Rules) -> Result<Tournament, &'static str> {
+        if players.len() < TABLE_SIZE {
+            return Err("Not enough players for a table");
+        }
+
+        let mut ids = players.clone();
+        ids.sort();
+        ids.dedup();
+        if ids.len() != players.len() {
+            return Err("Players must be unique");
+        }
+
+        let standings = players.iter().map(|&player| Standing {
+            player,
+            points: 0,
+            wins: 0,
+            games: 0,
+            byes: 0,
+            eliminated: false
+        }).collect();
+
+        Ok(Tournament {
+            format,
+            seed,
+            rules,
+            round: 0,
+            tables: vec!(),
+            byes: vec!(),
+            standings
+        })
+    }
+
+    /// seat players for the next round
+    pub fn start_round(&mut self) -> Result<Vec<Table>, &'static str> {
+        if self.is_finished() {
+            return Err("The tournament is over");
+        }
+
+        if !self.is_round_complete() {
+            return Err("The current round is still being played");
+        }
+
+        let mut players: Vec<u64> = self.get_standings().iter()
+            .filter(|s| !s.eliminated)
+            .map(|s| s.player)
+            .collect();
+
+        // nobody has a record in the first round, so seat at random
+        if self.round == 0 {
+            let mut rng: StdRng = SeedableRng::from_seed(&[self.seed as usize][..]);
+            rng.shuffle(&mut players);
+        }
+
+        let sizes = table_sizes(players.len());
+        let seated: usize = sizes.iter().sum();
+        let byes = self.choose_byes(&players, players.len() - seated);
+
+        players.retain(|p| !byes.contains(p));
+
+        self.round += 1;
+        let seed = self.seed.wrapping_add(self.round as u64);
+
+        let mut seats = players.iter();
+        self.tables = sizes.iter().map(|&size| Table {
+            players: seats.by_ref().take(size).cloned().collect(),
+            seed,
+            result: None
+        }).collect();
+
+        // a bye scores the same as finishing second
+        for player in &byes {
+            let standing = self.get_standing_mut(*player);
+            standing.byes += 1;
+            standing.points += TABLE_SIZE as u64 - 2;
+        }
+        self.byes = byes;
+
+        Ok(self.tables.clone())
+    }
+
+    /// the deal for a table in the current round
+    pub fn setup_table(&self, table: usize) -> Result<GameDefinition, &'static str> {
+        let t = self.tables.get(table).ok_or("No such table")?;
+        Game::setup_seeded(t.players.clone(), 1, self.rules.clone(), t.seed)
+    }
+
+    /// record the outcome of the game at a table
+    pub fn record_result(&mut self, table: usize, outcome: &Outcome) -> Result<(), &'static str> {
+        let t = self.tables.get(table).ok_or("No such table")?.clone();
+
+        if t.result.is_some() {
+            return Err("This table already has a result");
+        }
+
+        let mut expected = t.players.clone();
+        let mut given = outcome.finishing_order.clone();
+        expected.sort();
+        given.sort();
+        if expected != given {
+            return Err("The result does not match the players at the table");
+        }
+
+        let order = &outcome.finishing_order;
+        let knockout = self.format == Format::Knockout;
+        let final_table = knockout && self.byes.is_empty() && self.tables.len() == 1;
+
+        for (place, player) in order.iter().enumerate() {
+            let standing = self.get_standing_mut(*player);
+
+            standing.games += 1;
+            standing.points += (order.len() - 1 - place) as u64;
+            if place == 0 {
+                standing.wins += 1;
+            }
+
+            let goes_through = if final_table { place == 0 } else { place < order.len().div_ceil(2) };
+            if knockout && !goes_through {
+                standing.eliminated = true;
+            }
+        }
+
+        self.tables[table].result = Some(order.clone());
+        Ok(())
+    }
+
+    /// tables in the current round
+    pub fn get_tables(&self) -> Vec<Table> {
+        self.tables.clone()
+    }
+
+    /// players sitting out the current round
+    pub fn get_byes(&self) -> Vec<u64> {
+        self.byes.clone()
+    }
+
+    /// rounds started so far
+    pub fn get_round(&self) -> usize {
+        self.round
+    }
+
+    /// every player, best first
+    pub fn get_standings(&self) -> Vec<Standing> {
+        let mut standings = self.standings.clone();
+        standings.sort_by(|a, b| {
+            a.eliminated.cmp(&b.eliminated)
+                .then(b.points.cmp(&a.points))
+                .then(b.wins.cmp(&a.wins))
+                .then(a.player.cmp(&b.player))
+        });
+        standings
+    }
+
+    /// whether every table in the current round has a result
+    pub fn is_round_complete(&self) -> bool {
+        self.tables.iter().all(|t| t.result.is_some())
+    }
+
+    /// whether all rounds have been played
+    pub fn is_finished(&self) -> bool {
+        if !self.is_round_complete() {
+            return false;
+        }
+
+        match self.format {
+            Format::Swiss(rounds) => self.round >= rounds,
+            Format::Knockout => self.standings.iter().filter(|s| !s.eliminated).count() < 2
+        }
+    }
+
+    // byes go to the lowest placed players who have had the fewest
+    fn choose_byes(&self, players: &[u64], count: usize) -> Vec<u64> {
+        let mut candidates: Vec<(usize, usize, u64)> = players.iter().enumerate()
+            .map(|(position, &p)| (self.get_standing(p).byes, players.len() - position, p))
+            .collect();
+        candidates.sort();
+
+        candidates.iter().take(count).map(|&(_, _, p)| p).collect()
+    }
+
+    fn get_standing(&self, player: u64) -> &Standing {
+        self.standings.iter().find(|s| s.player == player).unwrap()
+    }
+
+    fn get_standing_mut(&mut self, player: u64) -> &mut Standing {
+        self.standings.iter_mut().find(|s| s.player == player).unwrap()
+    }
+}
+
+// full tables first, then tables of three for the players left over -
+// only five players cannot be seated that way, so one sits out
+fn table_sizes(players: usize) -> Vec<usize> {
+    if players <= TABLE_SIZE {
+        return vec!(players);
+    }
+
+    let short = (TABLE_SIZE - players % TABLE_SIZE) % TABLE_SIZE;
+    if short * (TABLE_SIZE - 1) > players {
+        return vec!(TABLE_SIZE; players / TABLE_SIZE);
+    }
+
+    let full = (players - short * (TABLE_SIZE - 1)) / TABLE_SIZE;
+    let mut sizes = vec!(TABLE_SIZE; full);
+    sizes.extend(vec!(TABLE_SIZE - 1; short));
+    sizes
+}
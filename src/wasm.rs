@@ -0,0 +1,75 @@
+//! javascript bindings for running the engine in the browser
+//!
+//! game states and cards are passed as json, in the same form
+//! as the serde representation of `GameDefinition` and `PlayerCard`
+
+use wasm_bindgen::prelude::*;
+
+use cards::card::{self, PlayerCard};
+use game::game::{Game, GameDefinition};
+
+/// deal a new game - `player_ids` is a json array of ids
+#[wasm_bindgen]
+pub fn setup(player_ids: &str, num_decks: usize) -> Result<String, JsValue> {
+    let ids: Vec<u64> = from_json(player_ids)?;
+    let game = Game::setup(ids, num_decks).map_err(to_error)?;
+    to_json(&game)
+}
+
+/// check a game state can be loaded, returning it as the engine sees it
+#[wasm_bindgen]
+pub fn load(state: &str) -> Result<String, JsValue> {
+    let game = load_game(state)?;
+    to_json(&game.export())
+}
+
+/// play `cards`, a json array of cards, returning the new state - a
+/// joker is played as a wildcard, and a bare joker is refused
+#[wasm_bindgen]
+pub fn player_move(state: &str, player_id: u64, cards: &str) -> Result<String, JsValue> {
+    let game = load_game(state)?;
+    let cards: Vec<PlayerCard> = from_json(cards)?;
+    let next = game.player_move(player_id, cards).map_err(to_error)?;
+    to_json(&next)
+}
+
+/// play `cards`, saying whether the player has announced the move
+/// leaves them with their last card
+#[wasm_bindgen]
+pub fn player_move_announcing(state: &str, player_id: u64, cards: &str, last_card: bool) -> Result<String, JsValue> {
+    let game = load_game(state)?;
+    let cards: Vec<PlayerCard> = from_json(cards)?;
+    let next = game.player_move_announcing(player_id, cards, last_card).map_err(to_error)?;
+    to_json(&next)
+}
+
+/// every move a player can make, as a json array - an empty move is a pass
+#[wasm_bindgen]
+pub fn legal_moves(state: &str, player_id: u64) -> Result<String, JsValue> {
+    let game = load_game(state)?;
+    to_json(&game.get_legal_moves(player_id))
+}
+
+/// parse cards such as "3C 10H *2S" into a json array of cards
+#[wasm_bindgen]
+pub fn parse_cards(text: &str) -> Result<String, JsValue> {
+    let cards = card::parse_cards(text).map_err(to_error)?;
+    to_json(&cards)
+}
+
+fn load_game(state: &str) -> Result<Game, JsValue> {
+    let definition: GameDefinition = from_json(state)?;
+    Game::load(definition).map_err(to_error)
+}
+
+fn from_json<T>(json: &str) -> Result<T, JsValue> where T: ::serde::de::DeserializeOwned {
+    ::serde_json::from_str(json).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+fn to_json<T>(value: &T) -> Result<String, JsValue> where T: ::serde::Serialize {
+    ::serde_json::to_string(value).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+fn to_error(e: &'static str) -> JsValue {
+    JsValue::from_str(e)
+}